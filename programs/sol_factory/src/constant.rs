@@ -1,4 +1,4 @@
-use anchor_lang::declare_id;
+use anchor_lang::{declare_id, solana_program::{pubkey::Pubkey, ed25519_program}};
 
 pub mod multisig_wallet {
     use super::*;
@@ -10,7 +10,74 @@ pub mod admin_wallet {
     declare_id!("ADM12HQ5G2EzSwWy2nN1xXMyGjaBULuuX9GTgW2FPwZK");
 }
 
-pub const ED25519_PROGRAM_ID: &str = "Ed25519SigVerify111111111111111111111111111";
+pub const ED25519_PROGRAM_ID: Pubkey = ed25519_program::ID; // Ed25519SigVerify111111111111111111111111111
+
+// First byte of the signed airdrop message, v1 layout: [version: u8][buyer: Pubkey] (no longer accepted, never expires)
+pub const AIRDROP_MESSAGE_V1: u8 = 1;
+// v2 layout: [version: u8][buyer: Pubkey][valid_until: i64 LE]
+pub const AIRDROP_MESSAGE_V2: u8 = 2;
+// Signature whitelist buys, layout: [version: u8][collection: Pubkey][buyer: Pubkey][valid_until: i64 LE], 
+// its own version byte so an airdrop message can't be replayed as a whitelist one
+pub const WHITELIST_MESSAGE_V1: u8 = 3;
+
+// Each placeholder takes ~10 CPIs (account, extensions, mint, metadata + 6 fields), 
+// more than 3 per transaction doesn't fit in the 1.4M CU limit
+pub const MAX_PLACEHOLDER_BATCH: usize = 3;
+
+// Collections listed per CollectionRegistryPage, a full page moves create_collection to the next page PDA
+pub const REGISTRY_PAGE_SIZE: usize = 64;
+
+// Compute units to request (ComputeBudgetProgram.setComputeUnitLimit) per placeholder created, 
+// with headroom for extra attributes and the interest bearing extension, 
+// update it with any change to the placeholder mint extensions or metadata fields
+pub const CREATE_PLACEHOLDER_CU_ESTIMATE: u32 = 400_000;
+
+// Collection layout version, bumped with every field added to Collection (see migrate_collection)
+pub const COLLECTION_VERSION: u8 = 17;
+
+// Custom traits written next to the standard placeholder metadata fields, capped to keep the mint rent predictable
+pub const MAX_EXTRA_ATTRIBUTES: usize = 8;
+pub const MAX_EXTRA_ATTRIBUTES_LEN: usize = 512; // total bytes of every key + value
+
+// Metadata keys the holder of an AI NFT can write with holder_update_field when the collection allows it
+pub const HOLDER_EDITABLE_FIELDS: [&str; 1] = ["nickname"];
+pub const MAX_HOLDER_FIELD_VALUE_LEN: usize = 32;
+
+pub const MAX_USERNAME_LEN: usize = 32; // Admin.username, the admin_state account is sized to it
+
+pub const MAX_SYMBOL_LEN: usize = 10; // placeholder symbol override, same limit as the Metaplex symbol
+
+pub const MAX_DESCRIPTION_LEN: usize = 200; // on-chain collection description for wallets that don't fetch the uri
+
+pub const MAX_COLLECTION_CREATORS: usize = 5; // delegated admins allowed to create placeholders for a collection
+
+// Collection.pause_reason codes, frontends map them to a message (other values are free for future reasons)
+pub const PAUSE_REASON_NONE: u8 = 0;
+pub const PAUSE_REASON_MAINTENANCE: u8 = 1;
+pub const PAUSE_REASON_INCIDENT: u8 = 2;
+
+// Protocol.feature_flags bits, every feature is enabled on the first protocol initialization
+pub const FEATURE_SPL_PRICING: u8 = 0;
+pub const FEATURE_BATCH_MINT: u8 = 1;
+pub const FEATURE_AIRDROP_CLAIMS: u8 = 2;
+pub const FEATURE_DIRECT_AIRDROP: u8 = 3;
+pub const FEATURE_METAPLEX_BRIDGE: u8 = 4;
+
+// verify_collection_integrity bits, a set bit means the invariant holds
+pub const INTEGRITY_SUPPLY: u8 = 0; // total_supply <= max_supply
+pub const INTEGRITY_SALE_TIME: u8 = 1; // 0 < sale_start_time < sale_end_time
+pub const INTEGRITY_PAUSE_WINDOW: u8 = 2; // pause_start <= pause_end, no pause_reason without a window
+pub const INTEGRITY_WHITELIST_CAP: u8 = 3; // whitelist_supply_cap <= max_supply and whitelist_minted within the cap
+pub const INTEGRITY_CREATORS: u8 = 4; // at most MAX_COLLECTION_CREATORS
+pub const INTEGRITY_DESCRIPTION: u8 = 5; // at most MAX_DESCRIPTION_LEN bytes
+pub const INTEGRITY_DUTCH_PRICES: u8 = 6; // Dutch collections: dutch_floor <= dutch_start_price
+pub const INTEGRITY_VERSION: u8 = 7; // migrated to the current COLLECTION_VERSION
+
+pub const TREASURY_TIMELOCK: i64 = 60 * 60 * 24; // 24h before a new treasury can be accepted
+
+pub const CLAIM_GRACE_PERIOD: i64 = 60 * 60 * 24 * 30; // 30 days after the sale end before unclaimed airdrops can be swept
+
+pub const PLACEHOLDER_MINT_AMOUNT: u64 = 1; // 1/1 placeholders, the expected post mint balance of the buyer ATA
 
 pub const ADMIN_FEE: u64 = 100000000; // 0.3 SOL
 // pub const ADMIN_PERCENTAGE: f32 = 0.3;
\ No newline at end of file
@@ -4,7 +4,7 @@ use crate::{
         Admin,
         Protocol
     },
-    constant,
+    constant::{self, MAX_USERNAME_LEN},
     errors::{SetupError, ProtocolError},
 };
 
@@ -13,16 +13,11 @@ use crate::{
 pub struct AdminInit<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
-    #[account(
-        seeds = [b"admin_state", admin.key().as_ref()],
-        bump
-    )]
-    pub admin_state: Option<Account<'info, Admin>>,
     pub new_admin: SystemAccount<'info>,
     #[account(
         init,
         payer = admin,
-        space = Admin::INIT_SPACE + 5,
+        space = Admin::INIT_SPACE + username.len(),
         seeds = [b"admin_state", new_admin.key().as_ref()],
         bump
     )]
@@ -46,9 +41,11 @@ impl<'info> AdminInit<'info> {
             Create a new Admin Ix:
 
             Some security check:
-            - Check if the account that is initializing the admin is the admin itself, if not 
-            it should be the mutlisig account that is the admin of the enitre protocol.
+            - Only the mutlisig account (super-admin of the enitre protocol) can create admins, 
+            an existing admin can't create new ones.
             - Save the Time of initialization to render it useless for the first 12h of initialization.
+            - The new admin_state is init'd, an admin that already exists can't be initialized again.
+            - The username is at most MAX_USERNAME_LEN bytes, the account is sized to it.
 
             What the Instruction does:
             - Initialize the new admin account with the username (so we can monitor who are the admin
@@ -56,8 +53,9 @@ impl<'info> AdminInit<'info> {
 
         */
         
-        require!(!self.protocol.locked, ProtocolError::ProtocolLocked);
-        require!(self.admin_state.is_some() || self.admin.key() == constant::multisig_wallet::id(), SetupError::Unauthorized);
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin.key() == constant::multisig_wallet::id(), SetupError::Unauthorized);
+        require!(username.len() <= MAX_USERNAME_LEN, ProtocolError::UsernameTooLong);
         
         self.new_admin_state.set_inner(Admin {
             publickey: self.new_admin.key(),
@@ -5,7 +5,7 @@ use crate::{
         Protocol
     },
     constant,
-    errors::{SetupError, ProtocolError},
+    errors::SetupError,
 };
 
 #[derive(Accounts)]
@@ -47,7 +47,7 @@ impl<'info> AdminRemove<'info> {
 
         */
         
-        require!(!self.protocol.locked, ProtocolError::ProtocolLocked);
+        self.protocol.ensure_not_locked()?;
         require!(self.primary_admin.key() == constant::multisig_wallet::id(), SetupError::Unauthorized);
     
         
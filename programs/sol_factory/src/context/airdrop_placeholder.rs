@@ -1,16 +1,8 @@
 use {
     anchor_lang::prelude::*,
     anchor_spl::{
-        token_2022::{
-            Token2022, 
-            spl_token_2022::{
-                instruction::AuthorityType,
-                state::Account as TokenAccount,
-                extension::StateWithExtensions,
-            }},
-        associated_token::{AssociatedToken, Create, create},
-        token::Token,  
-        token_interface::{MintTo, mint_to, set_authority, SetAuthority}
+        token_2022::Token2022,
+        associated_token::AssociatedToken,
     },
     solana_program::{
         // system_instruction, 
@@ -22,13 +14,14 @@ use {
         }
     },
 };
-use std::str::FromStr;
 use crate::{
     constant::{
-        self, ED25519_PROGRAM_ID
+        self, ED25519_PROGRAM_ID, AIRDROP_MESSAGE_V2, PLACEHOLDER_MINT_AMOUNT, multisig_wallet,
         // ADMIN_FEE
-    }, errors::{BuyingError, ProtocolError}, state::{Collection, Placeholder, Protocol}
+    }, errors::{BuyingError, ProtocolError, SetupError}, state::{Collection, Placeholder, Protocol, LazyMint},
+    events::AirdropExecuted,
 };
+use super::{ensure_lazy_mint, mint_placeholder, pay_mint_fee, observe_phase, advance_next_placeholder};
 
 #[derive(Accounts)]
 pub struct AirdropPlaceholder<'info> {
@@ -56,7 +49,7 @@ pub struct AirdropPlaceholder<'info> {
     pub buyer_mint_ata: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [b"placeholder", placeholder.collection.key().as_ref(), placeholder.id.to_le_bytes().as_ref()],
+        seeds = [b"placeholder", placeholder.collection.key().as_ref(), collection.placeholder_seed(placeholder.id).as_ref()],
         bump,
     )] 
     pub placeholder: Account<'info, Placeholder>,
@@ -73,16 +66,39 @@ pub struct AirdropPlaceholder<'info> {
     )]
     /// CHECK:
     pub auth: UncheckedAccount<'info>,
+    /// CHECK: metadata update authority of the collection mints when collection_metadata_authority is set
+    #[account(
+        seeds = [b"meta_auth", collection.key().as_ref()],
+        bump
+    )]
+    pub meta_auth: UncheckedAccount<'info>,
+    // Only needed to deliver a lazy placeholder whose mint isn't created yet (collection.lazy_mint)
+    #[account(
+        mut,
+        seeds = [b"lazy", placeholder.key().as_ref()],
+        bump
+    )]
+    pub lazy_mint: Option<Account<'info, LazyMint>>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token>,
+    // All placeholder mints are Token-2022, every CPI below goes through token_2022_program
     pub token_2022_program: Program<'info, Token2022>,
     #[account(
         seeds = [b"protocol"],
         bump,
     )]
     pub protocol: Account<'info, Protocol>,
+    #[account(
+        mut,
+        address = protocol.treasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+    // Only needed to force an airdrop through a collection pause
+    pub super_admin: Option<Signer<'info>>,
+    // Only needed when the collection has an airdrop_rent_payer, it funds the buyer ATA instead of the payer
+    #[account(mut)]
+    pub rent_payer: Option<Signer<'info>>,
     pub system_program: Program<'info, System>,
-    #[account(address = instructions::ID)]
+    #[account(address = instructions::ID @ ProtocolError::InstructionsSysvarMissing)]
     /// CHECK: InstructionsSysvar account
     instructions: UncheckedAccount<'info>,
 }
@@ -90,6 +106,8 @@ pub struct AirdropPlaceholder<'info> {
 impl<'info> AirdropPlaceholder<'info> {
     pub fn airdrop(
         &mut self,
+        force: bool,
+        campaign_id: u32,
         bumps: AirdropPlaceholderBumps,
     ) -> Result<()> {
 
@@ -99,32 +117,62 @@ impl<'info> AirdropPlaceholder<'info> {
 
             Some security check:
             - The admin_state.publickey must match the signing admin.
+            - The placeholder must belong to the passed collection, so the supply of another collection can't be counted.
+            - Airdrops honor the collection pause window like buys do, so a paused collection is fully frozen. 
+            force skips the pause check for remediation airdrops, it requires the multisig to co-sign as super_admin.
 
             What these Instructions do:
             - Creates a transfer of a placeholder NFT.
             - Invokes a transfer of SOL (price of mint + adminFee) from the buyer to the collection owner & admin.
+            - The admin pays the protocol mint_fee_lamports (if any) to the treasury.
+            - The buyer ATA rent is paid by the admin, or by the collection airdrop_rent_payer (ex. a funded ops wallet) 
+            which must then co-sign as rent_payer.
+            - Lazy placeholders (collection.lazy_mint) get their mint created first from the LazyMint PDA, the admin payer funds the mint rent. 
+            This adds the create_placeholder cost (~CREATE_PLACEHOLDER_CU_ESTIMATE, 400k CU) to the instruction, 
+            clients should request ~600k compute units with a ComputeBudgetProgram instruction.
             - Increase the total_supply on the collection (total minted nfts).
+            - require_memo_on_transfer is not applied: enabling MemoTransfer on the buyer ATA needs the buyer 
+            signature and the buyer doesn't sign an airdrop, the airdropped ATA stays without the memo requirement.
+            - Emits AirdropExecuted with the campaign_id tag (ex. early supporters, contest winners) 
+            so off-chain analytics can segment the airdrops, 0 for untagged airdrops.
 
             - Airdrop Functionality
-                - Attached to the instructions will be a ED25519 txn w/ a signature and message
-                - If signature matches admin, then the buyer will be airdropped the mint without paying the mint price
+                - Attached to the instructions will be a ED25519 txn w/ a signature and message, anywhere before the airdrop 
+                instruction (the closest preceding one is checked), a transaction without it is rejected 
+                with MissingSignatureInstruction
+                - If signature matches the protocol airdrop_signer, then the buyer will be airdropped the mint without paying the mint price
                 - The inputted buyer must match the buyer from the ED25519 message
+                - The buyer can't be the admin paying for the airdrop
+
+            - ED25519 instruction data: [0..16] header, [16..48] signer pubkey, [48..112] signature, [112..] message, 
+            the header must hold a single signature pointing at these offsets of the ED25519 instruction itself
+                - The first message byte is the version, unknown versions are rejected
+                - v2 message (exactly 41 bytes): [112] version = 2, [113..145] buyer pubkey, [145..153] valid_until (i64 LE)
+                - The signature is rejected once valid_until has passed, so a leaked message has a bounded lifetime
+                - v1 messages ([112] version = 1, [113..145] buyer pubkey) never expire and are rejected
         */
 
-        require!(!self.protocol.locked, ProtocolError::ProtocolLocked);
+        self.protocol.ensure_not_locked()?;
         require!(self.payer.key() == constant::admin_wallet::id(), ProtocolError::UnauthorizedAdmin);
-
-        let seeds: &[&[u8]; 2] = &[
-            b"auth",
-            &[bumps.auth],
-        ];
-        let signer_seeds = &[&seeds[..]];
-    
+        require!(self.buyer.key() != self.payer.key(), ProtocolError::SelfAirdrop);
+        require!(self.placeholder.collection == self.collection.key(), ProtocolError::CollectionMismatch);
 
         require!(
-            self.collection.max_supply > self.collection.total_supply,
+            !self.collection.is_sold_out(),
             BuyingError::SoldOut
         );
+
+        if force {
+            require!(
+                self.super_admin.as_ref().is_some_and(|super_admin| super_admin.key() == multisig_wallet::id()),
+                SetupError::Unauthorized
+            );
+        } else {
+            require!(
+                !self.collection.is_paused(Clock::get()?.unix_timestamp),
+                BuyingError::CollectionPaused
+            );
+        }
         
         // let transfer_instruction_two = system_instruction::transfer(
         //     &self.collection_owner.key(),
@@ -132,114 +180,267 @@ impl<'info> AirdropPlaceholder<'info> {
         //     ADMIN_FEE,
         // );
 
-         // Instruction Check
-         let ixs = self.instructions.to_account_info();
-         let current_index = load_current_index_checked(&ixs)? as usize;
-        
-         // If the current index is greater than 0, then we can check for the airdrop instructions
-         if current_index > 0 {
-             match load_instruction_at_checked(current_index - 1, &ixs) {
-                Ok(signature_ix) => {
-                    if Pubkey::from_str(ED25519_PROGRAM_ID).unwrap() == signature_ix.program_id {
-                         // Ensure signing authority is correct
-                       require!(
-                        constant::admin_wallet::id()
-                             .to_bytes()
-                             .eq(&signature_ix.data[16..48]),
-                         ProtocolError::UnauthorizedAdmin,
-                       );
- 
-                       let mut message_data: [u8; 32] = [0; 32];
-                       message_data.copy_from_slice(&signature_ix.data[112..144]);
-                       let _buyer = Pubkey::from(message_data);
-
-                       require!(
-                         _buyer == *self.buyer.key,
-                         ProtocolError::UnauthorizedAdmin,
-                       );
-
-                    //    invoke(
-                    //     &transfer_instruction_two,
-                    //     &[
-                    //         self.collection_owner.to_account_info(),
-                    //         self.payer.to_account_info(),
-                    //         self.system_program.to_account_info(),
-                    //     ],
-                    //     )?;
-            
-                        // Initialize ATA
-                        create(
-                        CpiContext::new(
-                            self.token_2022_program.to_account_info(),
-                            Create {
-                                payer: self.payer.to_account_info(), // payer
-                                associated_token: self.buyer_mint_ata.to_account_info(),
-                                authority: self.buyer.to_account_info(), // owner
-                                mint: self.mint.to_account_info(),
-                                system_program: self.system_program.to_account_info(),
-                                token_program: self.token_2022_program.to_account_info(),
-                            }
-                        ),
-                        )?;
-
-                        // balance before minting
-                        {
-                            let _before_data = self.buyer_mint_ata.data.borrow();
-                            let _before_state = StateWithExtensions::<TokenAccount>::unpack(&_before_data)?;
-                        
-                            // msg!("before mint balance={}", _before_state.base.amount);
-                        }
-                        
-            
-                        // Mint the mint
-                         mint_to(
-                        CpiContext::new_with_signer(
-                            self.token_2022_program.to_account_info(),
-                            MintTo {
-                                mint: self.mint.to_account_info(),
-                                to: self.buyer_mint_ata.to_account_info(),
-                                authority: self.auth.to_account_info(),
-                            },
-                            signer_seeds
-                        ),
-                        1,
-                        )?;
-                    
-                        self.collection.total_supply += 1;
-            
-                        set_authority(
-                            CpiContext::new_with_signer(
-                                self.token_2022_program.to_account_info(), 
-                                SetAuthority {
-                                    current_authority: self.auth.to_account_info(),
-                                    account_or_mint: self.mint.to_account_info(),
-                                }, 
-                                signer_seeds
-                            ), 
-                            AuthorityType::MintTokens, 
-                        None
-                        )?;
-
-                        // check the post balance of the mint
-                        {
-                            let _after_data = self.buyer_mint_ata.data.borrow();
-                            let _after_state = StateWithExtensions::<TokenAccount>::unpack(&_after_data)?;
-
-                            // msg!("after mint balance={}", _after_state.base.amount);
-
-                            require!(_after_state.base.amount == 1, ProtocolError::InvalidBalancePostMint);
-                        }
-                    } else {
-                        // NO ED25519 instruction
-                        Err(ProtocolError::InstructionsNotCorrect)?;
-                    }
-                }
-                Err(_) => {
-                    // NO ED25519 instruction
-                    Err(ProtocolError::InstructionsNotCorrect)?;
-                }
+        // Ensure signing authority is correct (rotatable, see ProtocolSetting::set_airdrop_signer)
+        let signature_data = load_signature_instruction(
+            &self.instructions.to_account_info(),
+            &self.protocol.airdrop_signer,
+        )?;
+
+        let _buyer = match signature_data[112] {
+            AIRDROP_MESSAGE_V2 => {
+                require!(signature_data.len() == 153, ProtocolError::InstructionsNotCorrect);
+
+                let mut valid_until_data: [u8; 8] = [0; 8];
+                valid_until_data.copy_from_slice(&signature_data[145..153]);
+                require!(
+                    Clock::get()?.unix_timestamp <= i64::from_le_bytes(valid_until_data),
+                    ProtocolError::SignatureExpired
+                );
+
+                let mut message_data: [u8; 32] = [0; 32];
+                message_data.copy_from_slice(&signature_data[113..145]);
+                Pubkey::from(message_data)
             }
-        }
+            _ => return Err(ProtocolError::UnsupportedSignatureVersion.into()),
+        };
+
+        require!(
+            _buyer == *self.buyer.key,
+            BuyingError::WalletDoesNotMatch,
+        );
+
+        // invoke(
+        //     &transfer_instruction_two,
+        //     &[
+        //         self.collection_owner.to_account_info(),
+        //         self.payer.to_account_info(),
+        //         self.system_program.to_account_info(),
+        //     ],
+        // )?;
+
+        let ata_rent_payer = match self.collection.airdrop_rent_payer {
+            None => self.payer.to_account_info(),
+            Some(airdrop_rent_payer) => {
+                let Some(rent_payer) = self.rent_payer.as_ref() else {
+                    return Err(ProtocolError::RentPayerMismatch.into());
+                };
+                require_keys_eq!(rent_payer.key(), airdrop_rent_payer, ProtocolError::RentPayerMismatch);
+                rent_payer.to_account_info()
+            }
+        };
+
+        pay_mint_fee(
+            &self.payer.to_account_info(),
+            &self.treasury.to_account_info(),
+            &self.system_program.to_account_info(),
+            self.protocol.mint_fee_lamports,
+        )?;
+
+        ensure_lazy_mint(
+            &self.payer.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.auth.to_account_info(),
+            &self.meta_auth.to_account_info(),
+            &self.token_2022_program.to_account_info(),
+            &self.collection,
+            &self.placeholder,
+            self.lazy_mint.as_ref(),
+            bumps.mint,
+            bumps.auth,
+            bumps.meta_auth,
+        )?;
+
+        mint_placeholder(
+            &ata_rent_payer,
+            &self.buyer.to_account_info(),
+            &self.buyer_mint_ata.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.auth.to_account_info(),
+            &self.token_2022_program.to_account_info(),
+            &self.system_program.to_account_info(),
+            PLACEHOLDER_MINT_AMOUNT,
+            bumps.auth,
+        )?;
+
+        observe_phase(&mut self.collection)?;
+        advance_next_placeholder(&mut self.collection, self.placeholder.id);
+
+        self.collection.total_supply += 1;
+
+        emit!(AirdropExecuted {
+            collection: self.collection.key(),
+            buyer: self.buyer.key(),
+            mint: self.mint.key(),
+            campaign_id,
+        });
+
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+// Instruction Check: the ED25519 instruction can be anywhere before the current one, the closest one is used 
+// instead of assuming it sits at current_index - 1. Shared by the airdrop and the signature whitelist buy, 
+// returns the ED25519 instruction data once the signer matches: [16..48] signer pubkey, [48..112] signature, 
+// [112..] message. The header must point the single signature at exactly these bytes of this instruction, 
+// otherwise the verified key & message could live elsewhere while forged bytes sit at the fixed offsets. 
+// The callers check the exact message length of their message version.
+pub fn load_signature_instruction(
+    instructions: &AccountInfo,
+    signer: &Pubkey,
+) -> Result<Vec<u8>> {
+    let current_index = load_current_index_checked(instructions)? as usize;
+
+    let mut signature_ix = None;
+    for index in (0..current_index).rev() {
+        let ix = load_instruction_at_checked(index, instructions)?;
+        if ix.program_id == ED25519_PROGRAM_ID {
+            signature_ix = Some(ix);
+            break;
+        }
+    }
+
+    // NO ED25519 instruction, distinct from a missing sysvar (rejected by the account constraints)
+    let signature_ix = signature_ix.ok_or(ProtocolError::MissingSignatureInstruction)?;
+
+    // Header, pubkey, signature and at least the version byte must be there before slicing
+    let data = &signature_ix.data;
+    require!(data.len() > 112, ProtocolError::InstructionsNotCorrect);
+
+    // Header: [0] num_signatures, [1] padding, then signature_offset, signature_instruction_index, 
+    // public_key_offset, public_key_instruction_index, message_data_offset, message_data_size, 
+    // message_instruction_index (u16 LE each), u16::MAX instruction indexes mean this instruction
+    let header_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+    require!(
+        data[0] == 1
+            && header_u16(2) == 48
+            && header_u16(4) == u16::MAX
+            && header_u16(6) == 16
+            && header_u16(8) == u16::MAX
+            && header_u16(10) == 112
+            && header_u16(12) as usize == data.len() - 112
+            && header_u16(14) == u16::MAX,
+        ProtocolError::InstructionsNotCorrect
+    );
+
+    require!(
+        signer.to_bytes().eq(&signature_ix.data[16..48]),
+        ProtocolError::UnauthorizedAdmin,
+    );
+
+    Ok(signature_ix.data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::{
+        instruction::{BorrowedAccountMeta, BorrowedInstruction},
+        sysvar::{self, instructions::{construct_instructions_data, store_current_index}},
+    };
+
+    // Single signature ED25519 instruction data pointing at itself, the precompile isn't run here
+    fn signature_ix_data(signer: &Pubkey, message: &[u8]) -> Vec<u8> {
+        let mut data = vec![1, 0];
+        for offset in [48, u16::MAX, 16, u16::MAX, 112, message.len() as u16, u16::MAX] {
+            data.extend_from_slice(&offset.to_le_bytes());
+        }
+        data.extend_from_slice(&signer.to_bytes());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    // Instructions sysvar data of a transaction with these (program, data) instructions, executing current_index
+    fn sysvar_data(transaction: &[(Pubkey, Vec<u8>)], current_index: u16) -> Vec<u8> {
+        let payer = Pubkey::new_unique();
+        let instructions: Vec<BorrowedInstruction> = transaction
+            .iter()
+            .map(|(program_id, data)| BorrowedInstruction {
+                program_id,
+                accounts: vec![BorrowedAccountMeta { pubkey: &payer, is_signer: true, is_writable: true }],
+                data,
+            })
+            .collect();
+        let mut data = construct_instructions_data(&instructions);
+        store_current_index(&mut data, current_index);
+        data
+    }
+
+    fn load(transaction: &[(Pubkey, Vec<u8>)], current_index: u16, signer: &Pubkey) -> Result<Vec<u8>> {
+        let mut data = sysvar_data(transaction, current_index);
+        let mut lamports = 0;
+        let instructions_info = AccountInfo::new(
+            &instructions::ID,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &sysvar::ID,
+            false,
+            0,
+        );
+        load_signature_instruction(&instructions_info, signer)
+    }
+
+    #[test]
+    fn signature_instruction_at_index_zero() {
+        let signer = Pubkey::new_unique();
+        let signature = signature_ix_data(&signer, &[AIRDROP_MESSAGE_V2]);
+        let transaction = [(ED25519_PROGRAM_ID, signature.clone()), (crate::ID, vec![])];
+
+        assert_eq!(load(&transaction, 1, &signer).unwrap(), signature);
+    }
+
+    #[test]
+    fn signature_instruction_two_positions_before() {
+        let signer = Pubkey::new_unique();
+        let signature = signature_ix_data(&signer, &[AIRDROP_MESSAGE_V2]);
+        let transaction = [
+            (ED25519_PROGRAM_ID, signature.clone()),
+            (Pubkey::new_unique(), vec![0]),
+            (crate::ID, vec![]),
+        ];
+
+        assert_eq!(load(&transaction, 2, &signer).unwrap(), signature);
+    }
+
+    #[test]
+    fn signature_instruction_after_the_airdrop_is_missing() {
+        let signer = Pubkey::new_unique();
+        let transaction = [
+            (crate::ID, vec![]),
+            (ED25519_PROGRAM_ID, signature_ix_data(&signer, &[AIRDROP_MESSAGE_V2])),
+        ];
+
+        assert_eq!(
+            load(&transaction, 0, &signer).unwrap_err(),
+            ProtocolError::MissingSignatureInstruction.into()
+        );
+    }
+
+    #[test]
+    fn closest_preceding_signature_instruction_wins() {
+        let signer = Pubkey::new_unique();
+        let other_signer = Pubkey::new_unique();
+        let signature = signature_ix_data(&signer, &[AIRDROP_MESSAGE_V2]);
+        let other_signature = signature_ix_data(&other_signer, &[AIRDROP_MESSAGE_V2]);
+
+        let transaction = [
+            (ED25519_PROGRAM_ID, other_signature.clone()),
+            (ED25519_PROGRAM_ID, signature.clone()),
+            (crate::ID, vec![]),
+        ];
+        assert_eq!(load(&transaction, 2, &signer).unwrap(), signature);
+
+        // the farther instruction of the right signer isn't looked at
+        let transaction = [
+            (ED25519_PROGRAM_ID, signature),
+            (ED25519_PROGRAM_ID, other_signature),
+            (crate::ID, vec![]),
+        ];
+        assert_eq!(
+            load(&transaction, 2, &signer).unwrap_err(),
+            ProtocolError::UnauthorizedAdmin.into()
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        program::invoke,
+        system_instruction,
+    },
+};
+use anchor_spl::token_2022::Token2022;
+use crate::state::{Collection, Protocol, Admin, Placeholder};
+use crate::errors::ProtocolError;
+use crate::constant::FEATURE_METAPLEX_BRIDGE;
+use super::{assert_inline_metadata, update_metadata_field};
+
+#[derive(Accounts)]
+pub struct BridgeMetaplexCollection<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"collection", collection.owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"placeholder", collection.key().as_ref(), collection.placeholder_seed(placeholder.id).as_ref()],
+        bump,
+    )] 
+    pub placeholder: Account<'info, Placeholder>,
+    /// CHECK: this is fine since the metadata update is checked by the Token-2022 program.
+    #[account(
+        mut,
+        seeds = [b"mint", placeholder.key().as_ref()],
+        bump
+    )]
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(
+        seeds = [b"auth"],
+        bump
+    )]
+    pub auth: UncheckedAccount<'info>,
+    /// CHECK: metadata update authority of the mints created while collection_metadata_authority was set
+    #[account(
+        seeds = [b"meta_auth", collection.key().as_ref()],
+        bump
+    )]
+    pub meta_auth: UncheckedAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BridgeMetaplexCollection<'info> {
+    pub fn bridge(
+        &mut self,
+        bumps: BridgeMetaplexCollectionBumps,
+    ) -> Result<()> {
+
+        /*
+        
+            Bridge Metaplex Collection Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+            - The collection must be opted in (collection.metaplex_collection set) and its metadata not frozen.
+            - The placeholder must belong to the collection.
+            - The mint metadata pointer must point to the mint itself (inline metadata).
+
+            What these Instructions do:
+            - Writes a "metaplex_collection" entry with the Metaplex collection mint into the placeholder 
+            Token-2022 metadata, so marketplaces keying off Metaplex collections can group the placeholders.
+            - The metadata realloc is funded by the admin (top up to rent exemption).
+            - The Metaplex verify_collection CPI is not done: it needs a Metaplex metadata account 
+            which Token-2022 placeholders don't have.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.protocol.is_feature_enabled(FEATURE_METAPLEX_BRIDGE), ProtocolError::FeatureDisabled);
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+        require!(!self.collection.frozen_metadata, ProtocolError::MetadataFrozen);
+        require!(self.placeholder.collection == self.collection.key(), ProtocolError::CollectionMismatch);
+
+        let Some(metaplex_collection) = self.collection.metaplex_collection else {
+            return Err(ProtocolError::MetaplexCollectionNotSet.into());
+        };
+
+        assert_inline_metadata(&self.mint.to_account_info())?;
+
+        update_metadata_field(
+            &self.token_2022_program.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.auth.to_account_info(),
+            &self.meta_auth.to_account_info(),
+            self.collection.key(),
+            "metaplex_collection".to_string(),
+            metaplex_collection.to_string(),
+            bumps.auth,
+            bumps.meta_auth,
+        )?;
+
+        // update_field reallocs the mint without transferring lamports, top it up to stay rent exempt
+        let rent_exempt = Rent::get()?.minimum_balance(self.mint.data_len());
+        let missing_lamports = rent_exempt.saturating_sub(self.mint.lamports());
+        if missing_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    &self.admin.key(),
+                    &self.mint.key(),
+                    missing_lamports,
+                ),
+                &[
+                    self.admin.to_account_info(),
+                    self.mint.to_account_info(),
+                    self.system_program.to_account_info(),
+                ],
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -5,24 +5,32 @@ use {
         token_2022::{
             Token2022, 
             spl_token_2022::{
-                instruction::AuthorityType,
-                state::Account as TokenAccount,
-                extension::StateWithExtensions,
+                instruction::{AuthorityType, reallocate},
+                state::{Account as TokenAccount, Mint as PlaceholderMint},
+                extension::{
+                    StateWithExtensions, ExtensionType,
+                    memo_transfer::instruction::enable_required_transfer_memos,
+                },
             }},
         associated_token::{AssociatedToken, Create, create},
         token::Token,  
-        token_interface::{MintTo, mint_to, set_authority, SetAuthority},
+        token_interface::{
+            MintTo, mint_to, set_authority, SetAuthority, thaw_account, ThawAccount, freeze_account, FreezeAccount,
+            Mint as PaymentMint, TokenAccount as PaymentTokenAccount, TokenInterface, transfer_checked, TransferChecked,
+        },
     },
-    solana_program::{system_instruction, program::invoke},
+    solana_program::{system_instruction, program::invoke, sysvar::instructions},
 };
 
 use crate::{
     constant::{
-        ADMIN_FEE, 
+        ADMIN_FEE, PLACEHOLDER_MINT_AMOUNT, FEATURE_SPL_PRICING, WHITELIST_MESSAGE_V1,
         // ADMIN_PERCENTAGE
     }, 
-    errors::{BuyingError, ProtocolError}, state::{Collection, Placeholder, Protocol}
+    errors::{BuyingError, ProtocolError}, state::{Collection, Placeholder, Protocol, LazyMint, SalePhase, SaleType, WhitelistReceipt},
+    events::PhaseEntered,
 };
+use super::{ensure_lazy_mint, load_signature_instruction};
 
 #[derive(Accounts)]
 pub struct BuyPlaceholder<'info> {
@@ -32,8 +40,11 @@ pub struct BuyPlaceholder<'info> {
     pub payer: Signer<'info>,
     #[account(mut)]
     pub collection: Account<'info, Collection>,
-    /// CHECK
-    #[account(mut)]
+    /// CHECK: receives the sale proceeds, the collection payout destination or else its owner
+    #[account(
+        mut,
+        address = collection.payout_destination.unwrap_or(collection.owner) @ ProtocolError::PayoutDestinationMismatch,
+    )]
     pub collection_owner: AccountInfo<'info>,
     #[account(
         mut,
@@ -49,7 +60,7 @@ pub struct BuyPlaceholder<'info> {
     pub buyer_mint_ata: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [b"placeholder", placeholder.collection.key().as_ref(), placeholder.id.to_le_bytes().as_ref()],
+        seeds = [b"placeholder", placeholder.collection.key().as_ref(), collection.placeholder_seed(placeholder.id).as_ref()],
         bump,
     )] 
     pub placeholder: Account<'info, Placeholder>,
@@ -66,6 +77,19 @@ pub struct BuyPlaceholder<'info> {
     )]
     /// CHECK:
     pub auth: UncheckedAccount<'info>,
+    /// CHECK: metadata update authority of the collection mints when collection_metadata_authority is set
+    #[account(
+        seeds = [b"meta_auth", collection.key().as_ref()],
+        bump
+    )]
+    pub meta_auth: UncheckedAccount<'info>,
+    // Only needed to deliver a lazy placeholder whose mint isn't created yet (collection.lazy_mint)
+    #[account(
+        mut,
+        seeds = [b"lazy", placeholder.key().as_ref()],
+        bump
+    )]
+    pub lazy_mint: Option<Account<'info, LazyMint>>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token>,
     pub token_2022_program: Program<'info, Token2022>,
@@ -74,12 +98,39 @@ pub struct BuyPlaceholder<'info> {
         bump,
     )]
     pub protocol: Account<'info, Protocol>,
+    #[account(
+        mut,
+        address = protocol.treasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+    // Only needed when the collection price is denominated in an SPL token (collection.price_mint)
+    pub payment_mint: Option<InterfaceAccount<'info, PaymentMint>>,
+    #[account(mut)]
+    pub buyer_payment_ata: Option<InterfaceAccount<'info, PaymentTokenAccount>>,
+    #[account(mut)]
+    pub owner_payment_ata: Option<InterfaceAccount<'info, PaymentTokenAccount>>,
+    pub payment_token_program: Option<Interface<'info, TokenInterface>>,
+    // Only needed when the collection is token gated (collection.gate_mint)
+    pub gate_token_account: Option<InterfaceAccount<'info, PaymentTokenAccount>>,
+    // Only needed for signature whitelist buys (whitelist phase & collection.signature_whitelist)
+    #[account(
+        init,
+        payer = payer,
+        space = WhitelistReceipt::INIT_SPACE,
+        seeds = [b"wl_receipt", collection.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub whitelist_receipt: Option<Account<'info, WhitelistReceipt>>,
+    /// CHECK: instructions sysvar, only needed for signature whitelist buys
+    #[account(address = instructions::ID @ BuyingError::WhitelistSignatureMissing)]
+    pub instructions: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> BuyPlaceholder<'info> {
     pub fn buy(
         &mut self,
+        expected_price: u64,
         bumps: BuyPlaceholderBumps,
     ) -> Result<()> {
 
@@ -89,20 +140,51 @@ impl<'info> BuyPlaceholder<'info> {
 
             Some security check:
             - The admin_state.publickey must match the signing admin.
+            - The placeholder must belong to the passed collection (price, owner payout and supply are read from it), CollectionMismatch otherwise.
 
             What these Instructions do:
             - Creates a transfer of a placeholder NFT.
-            - Invokes a transfer of SOL (price of mint + adminFee) from the buyer to the collection owner & admin.
+            - Invokes a transfer of SOL (price of mint + adminFee) from the buyer to the collection owner & protocol treasury.
+            - If the collection has a price_mint, the price is paid in that SPL token to the collection owner ATA 
+            and the adminFee is paid in SOL on top of it.
+            - The collection_owner account receiving the proceeds must be the collection payout destination if set, 
+            the collection owner otherwise (PayoutDestinationMismatch), the SPL owner_payment_ata must be owned by it.
+            - If the collection has a gate_mint, the buyer must pass a token account of that mint holding at least 1 token.
+            - Dutch collections (sale_type) are paid at the decayed collection price instead of the placeholder price.
+            - expected_price is the price the buyer signed for (lamports, or price_mint base units for SPL priced 
+            collections), if the applicable price differs the buy fails with PriceChanged instead of overcharging. 
+            A Dutch price decays every second, it only has to be at most the expected price.
+            - If the collection requires memos on transfer, the buyer ATA gets the MemoTransfer extension 
+            enabled (the buyer signs), every transfer into it then needs a memo instruction.
+            - During the whitelist phase (whitelist only collections, or until the whitelist_end_time / whitelist_supply_cap 
+            is reached) there is no public sale, only pre-authorized wallets (airdrops & claims) can mint. 
+            With signature_whitelist the buyer can also buy with an ED25519 instruction (anywhere before the buy, 
+            verified like airdrops) of the protocol airdrop_signer over [112] WHITELIST_MESSAGE_V1, [113..145] collection, 
+            [145..177] buyer, [177..185] valid_until (i64 LE). The WhitelistReceipt PDA [b"wl_receipt", collection, buyer] 
+            created by the buy (payer funded) limits it to one whitelist buy per wallet, and only these buys count against 
+            the whitelist_supply_cap.
+            - Invokes a transfer of the protocol mint_fee_lamports (if any) from the buyer to the treasury, 
+            the buyer balance must cover the price and every fee before anything is transferred.
+            - Emits PhaseEntered if this is the first mint of a new sale phase.
+            - Lazy placeholders (collection.lazy_mint) get their mint created first from the LazyMint PDA, the payer funds the mint rent. 
+            This adds the create_placeholder cost (~CREATE_PLACEHOLDER_CU_ESTIMATE, 400k CU) to the instruction, 
+            clients should request ~600k compute units with a ComputeBudgetProgram instruction.
             - Increase the total_supply on the collection (total minted nfts).
         */
 
-        require!(!self.protocol.locked, ProtocolError::ProtocolLocked);
+        self.protocol.ensure_not_locked()?;
+        require!(self.placeholder.collection == self.collection.key(), ProtocolError::CollectionMismatch);
 
         // make sure the current time is greater than the self.collection.sale_start_time 
         // and make sure the current time is less than the self.collection.sale_end_time
 
         let current_time = Clock::get()?.unix_timestamp;
 
+        if self.collection.phase(current_time) == SalePhase::Whitelist {
+            require!(self.collection.signature_whitelist, BuyingError::WhitelistOnly);
+            self.check_signature_whitelist(current_time)?;
+        }
+
         require!(
             current_time >= self.collection.sale_start_time,
             BuyingError::NotTimeYet
@@ -114,118 +196,502 @@ impl<'info> BuyPlaceholder<'info> {
         );
 
         require!(
-            self.collection.total_supply < self.collection.max_supply,
+            !self.collection.is_sold_out(),
             BuyingError::SoldOut
         );
 
-        let seeds: &[&[u8]; 2] = &[
-            b"auth",
-            &[bumps.auth],
-        ];
-        let signer_seeds = &[&seeds[..]];
-        
+        require!(
+            !self.collection.is_paused(current_time),
+            BuyingError::CollectionPaused
+        );
+
+        if let Some(gate_mint) = self.collection.gate_mint {
+            let Some(gate_token_account) = self.gate_token_account.as_ref() else {
+                return Err(BuyingError::GateNotSatisfied.into());
+            };
+
+            require_keys_eq!(gate_token_account.mint, gate_mint, BuyingError::GateNotSatisfied);
+            require_keys_eq!(gate_token_account.owner, self.buyer.key(), BuyingError::GateNotSatisfied);
+            require!(gate_token_account.amount >= 1, BuyingError::GateNotSatisfied);
+        }
+
+        // Dutch auctions replace the placeholder price with the decayed collection price
+        let price = self.collection.current_price(current_time, self.placeholder.price);
+
+        // The SOL price already includes the ADMIN_FEE, SPL priced collections only pay the ADMIN_FEE in SOL
+        let sol_due = match self.collection.price_mint {
+            None => (price * LAMPORTS_PER_SOL as f32) as u64,
+            Some(_) => ADMIN_FEE,
+        } + self.protocol.mint_fee_lamports;
+        require!(self.buyer.lamports() >= sol_due, BuyingError::InsufficientFunds);
 
         // Pay the mint
-        let amount_in_lamports = ((self.placeholder.price * LAMPORTS_PER_SOL as f32) as u64) - ADMIN_FEE;  //// ex. should be (0.3 * 1000000000) - 100000000 = 200000000
-        let transfer_instruction = system_instruction::transfer(
-            &self.buyer.key(),
-            &self.collection_owner.key(),
-            amount_in_lamports as u64,
-        );  
+        match self.collection.price_mint {
+            None => {
+                self.check_expected_price((price * LAMPORTS_PER_SOL as f32) as u64, expected_price)?;
+
+                let amount_in_lamports = ((price * LAMPORTS_PER_SOL as f32) as u64) - ADMIN_FEE;  //// ex. should be (0.3 * 1000000000) - 100000000 = 200000000
+                let transfer_instruction = system_instruction::transfer(
+                    &self.buyer.key(),
+                    &self.collection_owner.key(),
+                    amount_in_lamports as u64,
+                );  
+
+                invoke(
+                    &transfer_instruction,
+                    &[
+                        self.buyer.to_account_info(),
+                        self.collection_owner.to_account_info(),
+                        self.system_program.to_account_info(),
+                    ],
+                )?;
+            }
+            Some(price_mint) => {
+                require!(self.protocol.is_feature_enabled(FEATURE_SPL_PRICING), ProtocolError::FeatureDisabled);
+
+                let (Some(payment_mint), Some(buyer_payment_ata), Some(owner_payment_ata), Some(payment_token_program)) = (
+                    self.payment_mint.as_ref(),
+                    self.buyer_payment_ata.as_ref(),
+                    self.owner_payment_ata.as_ref(),
+                    self.payment_token_program.as_ref(),
+                ) else {
+                    return Err(BuyingError::PaymentAccountsMissing.into());
+                };
+
+                require_keys_eq!(payment_mint.key(), price_mint, BuyingError::TokenAccountMismatch);
+                require_keys_eq!(owner_payment_ata.mint, price_mint, BuyingError::TokenAccountMismatch);
+                // collection_owner is constrained to the payout destination (or owner), so is the ATA owner
+                require_keys_eq!(
+                    owner_payment_ata.owner,
+                    self.collection.payout_destination.unwrap_or(self.collection.owner),
+                    BuyingError::TokenAccountMismatch
+                );
+
+                let amount = (price * 10f32.powi(payment_mint.decimals as i32)) as u64;
+                self.check_expected_price(amount, expected_price)?;
+                transfer_checked(
+                    CpiContext::new(
+                        payment_token_program.to_account_info(),
+                        TransferChecked {
+                            from: buyer_payment_ata.to_account_info(),
+                            mint: payment_mint.to_account_info(),
+                            to: owner_payment_ata.to_account_info(),
+                            authority: self.buyer.to_account_info(),
+                        }
+                    ),
+                    amount,
+                    payment_mint.decimals,
+                )?;
+            }
+        }
 
         // let _admin_fee = ((self.placeholder.price * ADMIN_PERCENTAGE) * LAMPORTS_PER_SOL as f32) + ADMIN_FEE as f32;
 
         let transfer_instruction_two = system_instruction::transfer(
             &self.buyer.key(),
-            &self.payer.key(),
+            &self.treasury.key(),
             ADMIN_FEE as u64,
         );
 
-        
         invoke(
-            &transfer_instruction,
+            &transfer_instruction_two,
             &[
                 self.buyer.to_account_info(),
-                self.collection_owner.to_account_info(),
+                self.treasury.to_account_info(),
                 self.system_program.to_account_info(),
             ],
         )?;
 
-        invoke(
-            &transfer_instruction_two,
-            &[
-                self.buyer.to_account_info(),
-                self.payer.to_account_info(),
-                self.system_program.to_account_info(),
-            ],
+        pay_mint_fee(
+            &self.buyer.to_account_info(),
+            &self.treasury.to_account_info(),
+            &self.system_program.to_account_info(),
+            self.protocol.mint_fee_lamports,
         )?;
 
-        // Initialize ATA
-        create(
-            CpiContext::new(
-                self.token_2022_program.to_account_info(),
-                Create {
-                    payer: self.payer.to_account_info(), // payer
-                    associated_token: self.buyer_mint_ata.to_account_info(),
-                    authority: self.buyer.to_account_info(), // owner
-                    mint: self.mint.to_account_info(),
-                    system_program: self.system_program.to_account_info(),
-                    token_program: self.token_2022_program.to_account_info(),
-                }
-            ),
+        ensure_lazy_mint(
+            &self.payer.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.auth.to_account_info(),
+            &self.meta_auth.to_account_info(),
+            &self.token_2022_program.to_account_info(),
+            &self.collection,
+            &self.placeholder,
+            self.lazy_mint.as_ref(),
+            bumps.mint,
+            bumps.auth,
+            bumps.meta_auth,
         )?;
 
-        // balance before minting
-        {
-            let _before_data = self.buyer_mint_ata.data.borrow();
-            let _before_state = StateWithExtensions::<TokenAccount>::unpack(&_before_data)?;
-        
-            // msg!("before mint balance={}", _before_state.base.amount);
+        mint_placeholder(
+            &self.payer.to_account_info(),
+            &self.buyer.to_account_info(),
+            &self.buyer_mint_ata.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.auth.to_account_info(),
+            &self.token_2022_program.to_account_info(),
+            &self.system_program.to_account_info(),
+            PLACEHOLDER_MINT_AMOUNT,
+            bumps.auth,
+        )?;
+
+        if self.collection.require_memo_on_transfer {
+            require_transfer_memos(
+                &self.payer.to_account_info(),
+                &self.buyer.to_account_info(),
+                &self.buyer_mint_ata.to_account_info(),
+                &self.token_2022_program.to_account_info(),
+                &self.system_program.to_account_info(),
+            )?;
+        }
+
+        observe_phase(&mut self.collection)?;
+
+        advance_next_placeholder(&mut self.collection, self.placeholder.id);
+
+        self.collection.total_supply += 1;
+
+        Ok(())
+    }
+
+    // The airdrop_signer signed (collection, buyer) off-chain, the receipt created by this buy makes it single use. 
+    // Every failure is a BuyingError: the buyer fixes it by attaching a fresh, valid signature
+    fn check_signature_whitelist(
+        &mut self,
+        now: i64,
+    ) -> Result<()> {
+        let Some(instructions) = self.instructions.as_ref() else {
+            return Err(BuyingError::WhitelistSignatureMissing.into());
+        };
+        let Some(whitelist_receipt) = self.whitelist_receipt.as_mut() else {
+            return Err(BuyingError::NotWhitelisted.into());
+        };
+
+        let signature_data = load_signature_instruction(
+            &instructions.to_account_info(),
+            &self.protocol.airdrop_signer,
+        )
+        .map_err(|err| {
+            if err == Error::from(ProtocolError::MissingSignatureInstruction) {
+                Error::from(BuyingError::WhitelistSignatureMissing)
+            } else {
+                Error::from(BuyingError::InvalidWhitelistSignature)
+            }
+        })?;
+
+        require!(signature_data[112] == WHITELIST_MESSAGE_V1, BuyingError::InvalidWhitelistSignature);
+        require!(signature_data.len() == 185, BuyingError::InvalidWhitelistSignature);
+        require!(self.collection.key().to_bytes().eq(&signature_data[113..145]), BuyingError::NotWhitelisted);
+        require!(self.buyer.key().to_bytes().eq(&signature_data[145..177]), BuyingError::NotWhitelisted);
+
+        let mut valid_until_data: [u8; 8] = [0; 8];
+        valid_until_data.copy_from_slice(&signature_data[177..185]);
+        require!(now <= i64::from_le_bytes(valid_until_data), BuyingError::WhitelistSignatureExpired);
+
+        whitelist_receipt.set_inner(WhitelistReceipt {
+            collection: self.collection.key(),
+            buyer: self.buyer.key(),
+            minted_at: now,
+        });
+
+        record_whitelist_mint(&mut self.collection)
+    }
+
+    fn check_expected_price(
+        &self,
+        price_amount: u64,
+        expected_price: u64,
+    ) -> Result<()> {
+        match self.collection.sale_type {
+            SaleType::Fixed => require!(price_amount == expected_price, BuyingError::PriceChanged),
+            SaleType::Dutch => require!(price_amount <= expected_price, BuyingError::PriceChanged),
         }
+
+        Ok(())
+    }
+
+    pub fn buy_next(
+        &mut self,
+        expected_price: u64,
+        bumps: BuyPlaceholderBumps,
+    ) -> Result<()> {
+
+        /*
         
+            Buy Next Placeholder Ix:
+
+            Some security check:
+            - The placeholder must be the collection cursor (collection.next_placeholder_id), clients derive it 
+            from the cursor instead of tracking ids. If the next placeholder isn't created yet 
+            the placeholder account fails with AccountNotInitialized.
+            - Every delivery of the cursor placeholder moves the cursor, but an out of order delivery 
+            (buy_placeholder, airdrop or claim of a later id) can still leave the cursor on a sold placeholder 
+            once it gets there: the buy fails with NextPlaceholderSold and the admin moves the cursor 
+            with set_next_placeholder_id.
+
+            What these Instructions do:
+            - Same as buy_placeholder, the buy moves the cursor to the next id.
+        */
+
+        require!(
+            self.placeholder.id == self.collection.next_placeholder_id,
+            BuyingError::NotNextPlaceholder
+        );
+        require!(!is_placeholder_sold(&self.mint.to_account_info())?, BuyingError::NextPlaceholderSold);
+
+        self.buy(expected_price, bumps)
+    }
+    
+}
+
+// Moves the buy_next cursor past the delivered placeholder when it was the cursor one, 
+// called by every delivery path (buys, airdrops, claims). Out of order deliveries don't move it, 
+// buy_next detects a sold cursor placeholder (is_placeholder_sold)
+pub fn advance_next_placeholder(
+    collection: &mut Account<Collection>,
+    id: u64,
+) {
+    if id == collection.next_placeholder_id {
+        collection.next_placeholder_id += 1;
+    }
+}
+
+// A delivered placeholder has its supply minted, a lazy placeholder without a mint yet isn't sold
+pub fn is_placeholder_sold(
+    mint: &AccountInfo,
+) -> Result<bool> {
+    if mint.data_is_empty() {
+        return Ok(false);
+    }
+
+    let mint_data = mint.data.borrow();
+    let mint_state = StateWithExtensions::<PlaceholderMint>::unpack(&mint_data)?;
+
+    Ok(mint_state.base.supply > 0)
+}
+
+// Sends the protocol flat mint fee to the treasury, a zero fee is a no-op
+pub fn pay_mint_fee<'info>(
+    payer: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    mint_fee_lamports: u64,
+) -> Result<()> {
+    if mint_fee_lamports == 0 {
+        return Ok(());
+    }
+
+    invoke(
+        &system_instruction::transfer(
+            &payer.key(),
+            &treasury.key(),
+            mint_fee_lamports,
+        ),
+        &[
+            payer.clone(),
+            treasury.clone(),
+            system_program.clone(),
+        ],
+    )
+    .map_err(Into::into)
+}
+
+// The phase is time derived, the first mint in a new phase records it and emits PhaseEntered 
+// so clients get an on-chain signal of the transition
+pub fn observe_phase(
+    collection: &mut Account<Collection>,
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let phase = collection.phase(timestamp);
 
-        // Mint the mint
-        mint_to(
+    if phase != collection.last_observed_phase {
+        collection.last_observed_phase = phase;
+
+        emit!(PhaseEntered {
+            collection: collection.key(),
+            phase,
+            timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+// Counts the signature whitelist buys against collection.whitelist_supply_cap (0 = no separate cap), 
+// airdrops and claims are pre-authorized by the admin and don't consume the whitelist allocation
+pub fn record_whitelist_mint(
+    collection: &mut Account<Collection>,
+) -> Result<()> {
+    if collection.phase(Clock::get()?.unix_timestamp) != SalePhase::Whitelist {
+        return Ok(());
+    }
+
+    require!(
+        collection.whitelist_supply_cap == 0 || collection.whitelist_minted < collection.whitelist_supply_cap,
+        BuyingError::WhitelistSoldOut
+    );
+    collection.whitelist_minted += 1;
+
+    Ok(())
+}
+
+// Mints the placeholder to the buyer ATA (created by the payer) and removes the mint authority, 
+// shared by every path delivering a placeholder (buy, airdrops, claims). 
+// The ATA must be empty before and hold exactly amount after (PLACEHOLDER_MINT_AMOUNT for 1/1s), owned by the buyer
+#[allow(clippy::too_many_arguments)]
+pub fn mint_placeholder<'info>(
+    payer: &AccountInfo<'info>,
+    buyer: &AccountInfo<'info>,
+    buyer_mint_ata: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    auth: &AccountInfo<'info>,
+    token_2022_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+    auth_bump: u8,
+) -> Result<()> {
+    let seeds: &[&[u8]; 2] = &[
+        b"auth",
+        &[auth_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Initialize ATA
+    create(
+        CpiContext::new(
+            token_2022_program.clone(),
+            Create {
+                payer: payer.clone(), // payer
+                associated_token: buyer_mint_ata.clone(),
+                authority: buyer.clone(), // owner
+                mint: mint.clone(),
+                system_program: system_program.clone(),
+                token_program: token_2022_program.clone(),
+            }
+        ),
+    )?;
+
+    // balance before minting
+    let frozen = {
+        let _before_data = buyer_mint_ata.data.borrow();
+        let _before_state = StateWithExtensions::<TokenAccount>::unpack(&_before_data)?;
+    
+        // msg!("before mint balance={}", _before_state.base.amount);
+
+        require!(_before_state.base.amount == 0, ProtocolError::InvalidBalancePreMint);
+
+        _before_state.base.is_frozen()
+    };
+
+    // Mints created with a Frozen default account state need a thaw before minting
+    if frozen {
+        thaw_account(
             CpiContext::new_with_signer(
-                self.token_2022_program.to_account_info(),
-                MintTo {
-                    mint: self.mint.to_account_info(),
-                    to: self.buyer_mint_ata.to_account_info(),
-                    authority: self.auth.to_account_info(),
+                token_2022_program.clone(),
+                ThawAccount {
+                    account: buyer_mint_ata.clone(),
+                    mint: mint.clone(),
+                    authority: auth.clone(),
                 },
                 signer_seeds
             ),
-            1,
-        )?;    
-
-        self.collection.total_supply += 1;
+        )?;
+    }
+    
 
-        // msg!("Total supply: {}", self.collection.total_supply);
+    // Mint the mint
+    mint_to(
+        CpiContext::new_with_signer(
+            token_2022_program.clone(),
+            MintTo {
+                mint: mint.clone(),
+                to: buyer_mint_ata.clone(),
+                authority: auth.clone(),
+            },
+            signer_seeds
+        ),
+        amount,
+    )?;    
 
-        set_authority(
+    if frozen {
+        freeze_account(
             CpiContext::new_with_signer(
-                self.token_2022_program.to_account_info(), 
-                SetAuthority {
-                    current_authority: self.auth.to_account_info(),
-                    account_or_mint: self.mint.to_account_info(),
-                }, 
+                token_2022_program.clone(),
+                FreezeAccount {
+                    account: buyer_mint_ata.clone(),
+                    mint: mint.clone(),
+                    authority: auth.clone(),
+                },
                 signer_seeds
-            ), 
-            AuthorityType::MintTokens, 
-            None
+            ),
         )?;
+    }
 
-        // check the post balance of the mint
-        {
-            let _after_data = self.buyer_mint_ata.data.borrow();
-            let _after_state = StateWithExtensions::<TokenAccount>::unpack(&_after_data)?;
+    set_authority(
+        CpiContext::new_with_signer(
+            token_2022_program.clone(), 
+            SetAuthority {
+                current_authority: auth.clone(),
+                account_or_mint: mint.clone(),
+            }, 
+            signer_seeds
+        ), 
+        AuthorityType::MintTokens, 
+        None
+    )?;
 
-            // msg!("after mint balance={}", _after_state.base.amount);
+    // check the post balance of the mint
+    {
+        let _after_data = buyer_mint_ata.data.borrow();
+        let _after_state = StateWithExtensions::<TokenAccount>::unpack(&_after_data)?;
 
-            require!(_after_state.base.amount == 1, ProtocolError::InvalidBalancePostMint);
-        }
+        // msg!("after mint balance={}", _after_state.base.amount);
 
-        Ok(())
+        require!(_after_state.base.amount == amount, ProtocolError::InvalidBalancePostMint);
+        require_keys_eq!(_after_state.base.owner, buyer.key(), ProtocolError::AtaOwnerMismatch);
     }
-    
-}
\ No newline at end of file
+
+    Ok(())
+}
+
+// Token-2022 MemoTransfer is a token account extension (not a mint one), only the account owner can enable it: 
+// the buyer must sign, the payer funds the ATA realloc. Transfers into the ATA then require a memo instruction, 
+// the holder can still disable it later.
+pub fn require_transfer_memos<'info>(
+    payer: &AccountInfo<'info>,
+    buyer: &AccountInfo<'info>,
+    buyer_mint_ata: &AccountInfo<'info>,
+    token_2022_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    invoke(
+        &reallocate(
+            &token_2022_program.key(),
+            &buyer_mint_ata.key(),
+            &payer.key(),
+            &buyer.key(),
+            &[],
+            &[ExtensionType::MemoTransfer],
+        )?,
+        &[
+            buyer_mint_ata.clone(),
+            payer.clone(),
+            system_program.clone(),
+            buyer.clone(),
+        ],
+    )?;
+
+    invoke(
+        &enable_required_transfer_memos(
+            &token_2022_program.key(),
+            &buyer_mint_ata.key(),
+            &buyer.key(),
+            &[],
+        )?,
+        &[
+            buyer_mint_ata.clone(),
+            buyer.clone(),
+        ],
+    )
+    .map_err(Into::into)
+}
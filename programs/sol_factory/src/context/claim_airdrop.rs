@@ -0,0 +1,168 @@
+use {
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token_2022::Token2022,
+        associated_token::AssociatedToken,
+    },
+};
+use crate::{
+    constant::{PLACEHOLDER_MINT_AMOUNT, FEATURE_AIRDROP_CLAIMS}, errors::{BuyingError, ProtocolError}, state::{AirdropClaim, Collection, Placeholder, Protocol, LazyMint}
+};
+use super::{ensure_lazy_mint, mint_placeholder, pay_mint_fee, observe_phase, advance_next_placeholder, require_transfer_memos};
+
+#[derive(Accounts)]
+pub struct ClaimAirdrop<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"claim", collection.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, AirdropClaim>,
+    #[account(mut)]
+    pub collection: Account<'info, Collection>,
+    #[account(
+        mut,
+        seeds = [
+            buyer.key().as_ref(),
+            token_2022_program.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        seeds::program = associated_token_program.key(),
+        bump
+    )]
+    /// CHECK
+    pub buyer_mint_ata: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"placeholder", placeholder.collection.key().as_ref(), collection.placeholder_seed(placeholder.id).as_ref()],
+        bump,
+    )] 
+    pub placeholder: Account<'info, Placeholder>,
+    #[account(
+        mut,
+        seeds = [b"mint", placeholder.key().as_ref()],
+        bump
+    )]
+    /// CHECK
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"auth"],
+        bump
+    )]
+    /// CHECK:
+    pub auth: UncheckedAccount<'info>,
+    /// CHECK: metadata update authority of the collection mints when collection_metadata_authority is set
+    #[account(
+        seeds = [b"meta_auth", collection.key().as_ref()],
+        bump
+    )]
+    pub meta_auth: UncheckedAccount<'info>,
+    // Only needed to deliver a lazy placeholder whose mint isn't created yet (collection.lazy_mint)
+    #[account(
+        mut,
+        seeds = [b"lazy", placeholder.key().as_ref()],
+        bump
+    )]
+    pub lazy_mint: Option<Account<'info, LazyMint>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    #[account(
+        mut,
+        address = protocol.treasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimAirdrop<'info> {
+    pub fn claim(
+        &mut self,
+        bumps: ClaimAirdropBumps,
+    ) -> Result<()> {
+
+        /*
+        
+            Claim Airdrop Ix:
+
+            Some security check:
+            - The claim PDA is derived from the collection and the signing buyer, it only exists if an admin created it.
+            - The placeholder must belong to the claimed collection (CollectionMismatch).
+            - The claim must not be past its claim_deadline.
+
+            What these Instructions do:
+            - Mints the placeholder NFT to the buyer, the buyer pays the transaction & ATA rent 
+            and the protocol mint_fee_lamports (if any).
+            - If the collection requires memos on transfer, enables MemoTransfer on the buyer ATA (the buyer signs).
+            - Closes the claim (single use), the claim rent goes to the buyer.
+            - Lazy placeholders (collection.lazy_mint) get their mint created first from the LazyMint PDA, the buyer funds the mint rent. 
+            This adds the create_placeholder cost (~CREATE_PLACEHOLDER_CU_ESTIMATE, 400k CU) to the instruction, 
+            clients should request ~600k compute units with a ComputeBudgetProgram instruction.
+            - Increase the total_supply on the collection (total minted nfts).
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.protocol.is_feature_enabled(FEATURE_AIRDROP_CLAIMS), ProtocolError::FeatureDisabled);
+        require!(!self.claim.is_expired(Clock::get()?.unix_timestamp), ProtocolError::ClaimWindowClosed);
+        require!(self.placeholder.collection == self.collection.key(), ProtocolError::CollectionMismatch);
+        require!(!self.collection.is_sold_out(), BuyingError::SoldOut);
+        require!(!self.collection.is_paused(Clock::get()?.unix_timestamp), BuyingError::CollectionPaused);
+
+        pay_mint_fee(
+            &self.buyer.to_account_info(),
+            &self.treasury.to_account_info(),
+            &self.system_program.to_account_info(),
+            self.protocol.mint_fee_lamports,
+        )?;
+
+        ensure_lazy_mint(
+            &self.buyer.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.auth.to_account_info(),
+            &self.meta_auth.to_account_info(),
+            &self.token_2022_program.to_account_info(),
+            &self.collection,
+            &self.placeholder,
+            self.lazy_mint.as_ref(),
+            bumps.mint,
+            bumps.auth,
+            bumps.meta_auth,
+        )?;
+
+        mint_placeholder(
+            &self.buyer.to_account_info(),
+            &self.buyer.to_account_info(),
+            &self.buyer_mint_ata.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.auth.to_account_info(),
+            &self.token_2022_program.to_account_info(),
+            &self.system_program.to_account_info(),
+            PLACEHOLDER_MINT_AMOUNT,
+            bumps.auth,
+        )?;
+
+        if self.collection.require_memo_on_transfer {
+            require_transfer_memos(
+                &self.buyer.to_account_info(),
+                &self.buyer.to_account_info(),
+                &self.buyer_mint_ata.to_account_info(),
+                &self.token_2022_program.to_account_info(),
+                &self.system_program.to_account_info(),
+            )?;
+        }
+
+        observe_phase(&mut self.collection)?;
+        advance_next_placeholder(&mut self.collection, self.placeholder.id);
+
+        self.collection.total_supply += 1;
+
+        Ok(())
+    }
+}
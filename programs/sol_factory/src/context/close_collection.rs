@@ -13,7 +13,7 @@ pub struct CloseCollection<'info> {
     #[account(
         mut,
         seeds = [b"collection", owner.key().as_ref()],
-        bump,
+        bump = collection.bump,
     )] 
     pub collection: Account<'info, Collection>,
     #[account(
@@ -45,7 +45,7 @@ impl<'info> CloseCollection<'info> {
             - Closes the collection by updating the sale end time to the current moment and setting the max supply to the total supply.
         */
 
-        require!(!self.protocol.locked, ProtocolError::ProtocolLocked);
+        self.protocol.ensure_not_locked()?;
         require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
 
 
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol};
+
+#[derive(Accounts)]
+pub struct CompactCollection<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+        realloc = collection.space(),
+        realloc::payer = owner,
+        realloc::zero = false,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CompactCollection<'info> {
+    pub fn compact(
+        &mut self,
+    ) -> Result<()> {
+
+        /*
+        
+            Compact Collection Ix:
+
+            Some security check:
+            - The collection PDA is derived from the signing owner.
+
+            What these Instructions do:
+            - Reallocs the collection down to the exact space needed by its current fields 
+            (never below Collection::INIT_SPACE), the freed rent is returned to the owner by the realloc.
+        */
+
+        self.protocol.ensure_not_locked()?;
+
+        Ok(())
+    }
+}
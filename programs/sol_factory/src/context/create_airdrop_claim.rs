@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{AirdropClaim, Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+use crate::constant::FEATURE_AIRDROP_CLAIMS;
+
+#[derive(Accounts)]
+pub struct CreateAirdropClaim<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"collection", collection.owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    pub buyer: SystemAccount<'info>,
+    #[account(
+        init,
+        payer = admin,
+        space = AirdropClaim::INIT_SPACE,
+        seeds = [b"claim", collection.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, AirdropClaim>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateAirdropClaim<'info> {
+    pub fn create(
+        &mut self,
+        claim_deadline: i64,
+    ) -> Result<()> {
+
+        /*
+        
+            Create Airdrop Claim Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+
+            What these Instructions do:
+            - Pre-authorizes the buyer to claim one placeholder of the collection with claim_airdrop, 
+            the buyer pays for the mint transaction instead of the admin.
+            - claim_deadline (0 = none) closes the claim window, an expired claim can't be claimed and can be swept.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.protocol.is_feature_enabled(FEATURE_AIRDROP_CLAIMS), ProtocolError::FeatureDisabled);
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+
+        self.claim.set_inner(AirdropClaim {
+            collection: self.collection.key(),
+            buyer: self.buyer.key(),
+            created_at: Clock::get()?.unix_timestamp,
+            claim_deadline,
+        });
+
+        Ok(())
+    }
+}
@@ -1,13 +1,18 @@
 use anchor_lang::{
     solana_program::{
-        sysvar::rent::ID as RENT_ID,
-        program::{invoke, invoke_signed}
+        program::{invoke, invoke_signed},
+        native_token::LAMPORTS_PER_SOL,
     },
     prelude::*
 };
-pub use anchor_spl::token_2022::Token2022;
-use crate::state::{Collection, Protocol, Admin};
+pub use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::Mint,
+};
+use crate::state::{Collection, Protocol, Admin, SalePhase, SaleType, ReferenceIndex, CollectionRegistry, CollectionRegistryPage};
 use crate::errors::ProtocolError;
+use crate::events::CollectionCreated;
+use crate::constant::{ADMIN_FEE, COLLECTION_VERSION, MAX_DESCRIPTION_LEN, PAUSE_REASON_NONE};
 pub use spl_token_2022::{
     extension::ExtensionType,
     extension::group_pointer::instruction::initialize as initialize_group_pointer,
@@ -24,6 +29,9 @@ pub use spl_token_2022::{
     max_supply: u64,
     price: f32,
     stable_id: String,
+    salt: Option<[u8; 8]>,
+    interest_rate: Option<i16>,
+    description: String,
 )]
 pub struct CreateCollection<'info> {
     #[account(mut)]
@@ -36,9 +44,33 @@ pub struct CreateCollection<'info> {
         seeds = [b"collection", owner.key().as_ref()],
         bump,
         payer = admin,
-        space = Collection::INIT_SPACE + 54 + url.len() + name.len() + stable_id.len(),
+        space = Collection::INIT_SPACE + 54 + url.len() + name.len() + stable_id.len() + description.len(),
     )] 
     pub collection: Account<'info, Collection>,
+    #[account(
+        init,
+        seeds = [b"reference", reference.as_ref()],
+        bump,
+        payer = admin,
+        space = ReferenceIndex::INIT_SPACE,
+    )]
+    pub reference_index: Account<'info, ReferenceIndex>,
+    #[account(
+        init_if_needed,
+        seeds = [b"registry"],
+        bump,
+        payer = admin,
+        space = CollectionRegistry::INIT_SPACE,
+    )]
+    pub registry: Account<'info, CollectionRegistry>,
+    #[account(
+        init_if_needed,
+        seeds = [b"registry_page", registry.current_page().to_le_bytes().as_ref()],
+        bump,
+        payer = admin,
+        space = CollectionRegistryPage::INIT_SPACE,
+    )]
+    pub registry_page: Account<'info, CollectionRegistryPage>,
     #[account(
         seeds = [b"admin_state", admin.key().as_ref()],
         bump
@@ -51,9 +83,8 @@ pub struct CreateCollection<'info> {
         bump
     )]
     pub mint: UncheckedAccount<'info>,
-    #[account(address = RENT_ID)]
-    /// CHECK: this is fine since we are hard coding the rent sysvar.
-    pub rent: UncheckedAccount<'info>,
+    // SPL token the price is denominated in, none means native SOL
+    pub price_mint: Option<InterfaceAccount<'info, Mint>>,
     pub token_2022_program: Program<'info, Token2022>,
     #[account(
         seeds = [b"protocol"],
@@ -75,6 +106,9 @@ impl<'info> CreateCollection<'info> {
         max_supply: u64,
         price: f32,
         stable_id: String,
+        salt: Option<[u8; 8]>,
+        interest_rate: Option<i16>,
+        description: String,
         bumps: CreateCollectionBumps,
     ) -> Result<()> {
 
@@ -87,9 +121,23 @@ impl<'info> CreateCollection<'info> {
 
             What these Instructions do:
             - Creates a Collection that can be used to mint NFTs.
+            - Stores an optional salt that is mixed into every placeholder PDA of the collection,
+            so future placeholder (and mint) addresses can't be derived before the collection exists.
+            - Opt-in interest_rate (bps): placeholders of the collection are minted with the InterestBearingConfig 
+            extension (auth PDA as rate authority) to display an accruing amount, this is not plain NFT semantics.
+            - The optional price_mint account sets the SPL token the price is denominated in (none = native SOL), 
+            deserializing it as a Mint validates it exists.
+            - SOL priced collections can't be free or priced below the ADMIN_FEE (it is taken out of the price) 
+            and the protocol min_public_price.
+            - Creates the ReferenceIndex PDA [b"reference", reference] pointing to the collection, 
+            a reference can only be used by one collection.
+            - Records the creation time (created_at, never updated) and emits a CollectionCreated event.
+            - Appends the collection to the registry: the CollectionRegistryPage PDA [b"registry_page", page] 
+            of the current page (registry.total_collections / REGISTRY_PAGE_SIZE), the admin pays the rent 
+            of the registry head and of every new page. Collections created before the registry aren't listed.
         */
 
-        require!(!self.protocol.locked, ProtocolError::ProtocolLocked);
+        self.protocol.ensure_not_locked()?;
         require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
 
         // sanity check
@@ -99,6 +147,14 @@ impl<'info> CreateCollection<'info> {
         require!(sale_end_time > 0, ProtocolError::InvalidSaleTime);
         require!(max_supply > 0, ProtocolError::InvalidMaxSupply);
         require!(price >= 0.0, ProtocolError::InvalidPrice);
+        if self.price_mint.is_none() {
+            require!(price > 0.0, ProtocolError::InvalidPrice);
+            require!(
+                (price * LAMPORTS_PER_SOL as f32) as u64 >= ADMIN_FEE.max(self.protocol.min_public_price),
+                ProtocolError::InvalidPrice
+            );
+        }
+        require!(description.len() <= MAX_DESCRIPTION_LEN, ProtocolError::DescriptionTooLong);
 
         
         // msg!("Sale start time is {}", sale_start_time);
@@ -106,6 +162,8 @@ impl<'info> CreateCollection<'info> {
         // msg!("Current time is {}", Clock::get()?.unix_timestamp);
 
 
+        let created_at = Clock::get()?.unix_timestamp;
+
         self.collection.set_inner(
             Collection {
                 reference,
@@ -120,9 +178,56 @@ impl<'info> CreateCollection<'info> {
                 mint_count: 0,
                 price,
                 stable_id,
+                salt: salt.unwrap_or_default(),
+                payout_destination: None,
+                frozen_metadata: false,
+                interest_rate,
+                pause_start: 0,
+                pause_end: 0,
+                price_mint: self.price_mint.as_ref().map(|price_mint| price_mint.key()),
+                whitelist_only: false,
+                creators: Vec::new(),
+                metaplex_collection: None,
+                last_observed_phase: SalePhase::NotStarted,
+                gate_mint: None,
+                launch_verified: false,
+                version: COLLECTION_VERSION,
+                owner_can_create: false,
+                description,
+                next_placeholder_id: 0,
+                bump: bumps.collection,
+                sale_type: SaleType::Fixed,
+                dutch_start_price: 0.0,
+                dutch_floor: 0.0,
+                dutch_decay_per_sec: 0.0,
+                enforce_unique_uri: false,
+                created_at,
+                enable_holder_updates: false,
+                whitelist_supply_cap: 0,
+                whitelist_minted: 0,
+                pause_reason: PAUSE_REASON_NONE,
+                airdrop_rent_payer: None,
+                collection_metadata_authority: false,
+                require_memo_on_transfer: false,
+                lazy_mint: false,
+                signature_whitelist: false,
+                whitelist_end_time: 0,
             }
         );
 
+        self.reference_index.collection = self.collection.key();
+
+        self.registry_page.page = self.registry.current_page();
+        self.registry_page.collections.push(self.collection.key());
+        self.registry.total_collections += 1;
+
+        emit!(CollectionCreated {
+            collection: self.collection.key(),
+            owner: self.collection.owner,
+            reference,
+            created_at,
+        });
+
         // Step 1: Initialize Account
         let size = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
             &[
@@ -130,7 +235,7 @@ impl<'info> CreateCollection<'info> {
             ],
         ).unwrap();
 
-        let rent = &Rent::from_account_info(&self.rent.to_account_info())?;
+        let rent = Rent::get()?;
         let lamports = rent.minimum_balance(size );
 
         let collection_key = self.collection.key();
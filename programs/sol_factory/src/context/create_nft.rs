@@ -1,6 +1,5 @@
 pub use anchor_lang::{
     solana_program::{
-        sysvar::rent::ID as RENT_ID,
         program::{invoke, invoke_signed}
     },
     prelude::*
@@ -18,6 +17,7 @@ pub use spl_token_metadata_interface::{
 };
 pub use crate::state::{Protocol, Collection, Admin, AiNft, Attributes};
 pub use crate::errors::ProtocolError;
+use super::create_placeholder::{assert_mint_authority, assert_metadata_authority};
 
 #[derive(Accounts)]
 #[instruction(id: u64, uri: String, name: String, attributes: Vec<Attributes>)]
@@ -31,7 +31,7 @@ pub struct CreateNft<'info> {
     pub admin_state: Account<'info, Admin>,
     #[account(
         seeds = [b"collection", collection.owner.key().as_ref()],
-        bump,
+        bump = collection.bump,
     )] 
     pub collection: Account<'info, Collection>,
     /// CHECK: this is fine since we are handling all the checks and creation in the program.
@@ -62,9 +62,12 @@ pub struct CreateNft<'info> {
         bump
     )]
     pub auth: UncheckedAccount<'info>,
-    #[account(address = RENT_ID)]
-    /// CHECK: this is fine since we are hard coding the rent sysvar.
-    pub rent: UncheckedAccount<'info>,
+    /// CHECK: metadata update authority of the collection mints when collection_metadata_authority is set
+    #[account(
+        seeds = [b"meta_auth", collection.key().as_ref()],
+        bump
+    )]
+    pub meta_auth: UncheckedAccount<'info>,
     pub token_2022_program: Program<'info, Token2022>,
     #[account(
         seeds = [b"protocol"],
@@ -93,9 +96,11 @@ impl<'info> CreateNft<'info> {
 
             What these Instructions do:
             - Creates a AI NFT from the passed in uri, name, and attributes.
+            - The metadata update authority is the collection meta_auth PDA if the collection isolates 
+            its metadata (collection_metadata_authority), the global auth PDA otherwise.
         */
 
-        require!(!self.protocol.locked, ProtocolError::ProtocolLocked);
+        self.protocol.ensure_not_locked()?;
         require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
 
         self.nft.set_inner(
@@ -108,6 +113,15 @@ impl<'info> CreateNft<'info> {
             }
         );
 
+        let collection_key = self.collection.key();
+        let auth_seeds: &[&[u8]] = &[b"auth", &[bumps.auth]];
+        let meta_auth_seeds: &[&[u8]] = &[b"meta_auth", collection_key.as_ref(), &[bumps.meta_auth]];
+        let (update_authority, update_authority_seeds) = if self.collection.collection_metadata_authority {
+            (self.meta_auth.to_account_info(), meta_auth_seeds)
+        } else {
+            (self.auth.to_account_info(), auth_seeds)
+        };
+
         // Step 1: Initialize Account
         let size = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
             &[
@@ -120,7 +134,7 @@ impl<'info> CreateNft<'info> {
         ).unwrap();
 
         let metadata = TokenMetadata {
-            update_authority: spl_pod::optional_keys::OptionalNonZeroPubkey::try_from(Some(self.auth.key())).unwrap(),
+            update_authority: spl_pod::optional_keys::OptionalNonZeroPubkey::try_from(Some(update_authority.key())).unwrap(),
             mint: self.mint.key(),
             name: name.to_string(),
             symbol: self.collection.symbol.to_string(),
@@ -129,7 +143,7 @@ impl<'info> CreateNft<'info> {
         };
 
         let extension_extra_space = metadata.tlv_size_of().unwrap();
-        let rent = &Rent::from_account_info(&self.rent.to_account_info())?;
+        let rent = Rent::get()?;
         let lamports = rent.minimum_balance(size + extension_extra_space);
 
         let nft_key = self.nft.key();
@@ -173,7 +187,7 @@ impl<'info> CreateNft<'info> {
             &initialize_metadata_pointer(
             &self.token_2022_program.key(),
             &self.mint.key(),
-            Some(self.auth.key()),
+            Some(update_authority.key()),
             Some(self.mint.key()),
             )?,
             &vec![
@@ -196,17 +210,13 @@ impl<'info> CreateNft<'info> {
             signer_seeds
         )?;
 
-        let seeds: &[&[u8]; 2] = &[
-            b"auth",
-            &[bumps.auth],
-        ];
-        let signer_seeds = &[&seeds[..]];
+        assert_mint_authority(&self.mint.to_account_info(), &self.auth.key(), None)?;
 
         invoke_signed(
             &initialize_metadata_account(
                 &self.token_2022_program.key(),
                 &self.mint.key(),
-                &self.auth.key(),
+                &update_authority.key(),
                 &self.mint.key(),
                 &self.auth.key(),
                 metadata.name,
@@ -215,9 +225,10 @@ impl<'info> CreateNft<'info> {
             ),
             &vec![
                 self.mint.to_account_info(),
+                update_authority.clone(),
                 self.auth.to_account_info(),
             ],
-            signer_seeds
+            &[auth_seeds]
         )?;
 
         for (field, value) in metadata.additional_metadata.into_iter() {
@@ -225,18 +236,20 @@ impl<'info> CreateNft<'info> {
                 &update_metadata_account(
                     &self.token_2022_program.key(),
                     &self.mint.key(),
-                    &self.auth.key(),
+                    &update_authority.key(),
                     Field::Key(field),
                     value,
                 ),
                 &vec![
                     self.mint.to_account_info(),
-                    self.auth.to_account_info(),
+                    update_authority.clone(),
                 ],
-                signer_seeds
+                &[update_authority_seeds]
             )?;
         }
 
+        assert_metadata_authority(&self.mint.to_account_info(), &update_authority.key())?;
+
        Ok(())
     }
 }
\ No newline at end of file
@@ -1,25 +1,32 @@
 pub use anchor_lang::{
     solana_program::{
-        sysvar::rent::ID as RENT_ID,
-        program::{invoke, invoke_signed}
+        program::{invoke, invoke_signed},
+        program_option::COption,
+        hash::hash,
+        system_instruction,
     },
     prelude::*
 };
 pub use anchor_spl::token_2022::Token2022;
 pub use spl_token_2022::{
-    extension::ExtensionType,
+    extension::{ExtensionType, BaseStateWithExtensions, StateWithExtensions},
     instruction::{initialize_mint_close_authority, initialize_permanent_delegate, initialize_mint2},
-    extension::metadata_pointer::instruction::initialize as initialize_metadata_pointer,
+    extension::metadata_pointer::{MetadataPointer, instruction::initialize as initialize_metadata_pointer},
+    extension::default_account_state::instruction::initialize_default_account_state,
+    extension::interest_bearing_mint::instruction::initialize as initialize_interest_bearing_config,
+    state::AccountState,
 };
 pub use spl_token_metadata_interface::{
     state::{TokenMetadata, Field},
     instruction::{initialize as initialize_metadata_account, update_field as update_metadata_account},
 };
-pub use crate::state::{Protocol, Collection, Admin, Placeholder};
+pub use crate::state::{Protocol, Collection, Admin, Placeholder, Attributes, UriMarker, PlaceholderAddresses, LazyMint};
+use crate::constant::{MAX_EXTRA_ATTRIBUTES, MAX_EXTRA_ATTRIBUTES_LEN, MAX_SYMBOL_LEN};
 pub use crate::errors::{BuyingError, ProtocolError};
+use crate::events::PlaceholderCreated;
 
 #[derive(Accounts)]
-#[instruction(id: u64)]
+#[instruction(id: u64, uri: String)]
 pub struct CreatePlaceholder<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -27,18 +34,18 @@ pub struct CreatePlaceholder<'info> {
         seeds = [b"admin_state", admin.key().as_ref()],
         bump
     )]
-    pub admin_state: Account<'info, Admin>,
+    pub admin_state: Option<Account<'info, Admin>>,
     #[account(
         seeds = [b"collection", collection.owner.key().as_ref()],
-        bump,
+        bump = collection.bump,
     )] 
     pub collection: Account<'info, Collection>,
     #[account(
-        init,
+        init_if_needed,
         payer = admin,
-        seeds = [b"placeholder", collection.key().as_ref(), id.to_le_bytes().as_ref()],
+        seeds = [b"placeholder", collection.key().as_ref(), collection.placeholder_seed(id).as_ref()],
         bump,
-        space = Placeholder::INIT_SPACE + 32 + collection.name.len() + collection.symbol.len() + 8 + 8,
+        space = Placeholder::space(&collection),
     )] 
     pub placeholder: Account<'info, Placeholder>,
     /// CHECK: this is fine since we are handling all the checks and creation in the program.
@@ -48,15 +55,32 @@ pub struct CreatePlaceholder<'info> {
         bump
     )]
     pub mint: UncheckedAccount<'info>,
+    /// CHECK: created in the instruction, only needed when the collection enforces unique uris
+    #[account(
+        mut,
+        seeds = [b"uri", collection.key().as_ref(), hash(uri.as_bytes()).as_ref()],
+        bump
+    )]
+    pub uri_marker: Option<UncheckedAccount<'info>>,
+    /// CHECK: created in the instruction, only needed when the collection is in lazy mint mode
+    #[account(
+        mut,
+        seeds = [b"lazy", placeholder.key().as_ref()],
+        bump
+    )]
+    pub lazy_mint: Option<UncheckedAccount<'info>>,
     /// CHECK:
     #[account(
         seeds = [b"auth"],
         bump
     )]
     pub auth: UncheckedAccount<'info>,
-    #[account(address = RENT_ID)]
-    /// CHECK: this is fine since we are hard coding the rent sysvar.
-    pub rent: UncheckedAccount<'info>,
+    /// CHECK: metadata update authority of the collection mints when collection_metadata_authority is set
+    #[account(
+        seeds = [b"meta_auth", collection.key().as_ref()],
+        bump
+    )]
+    pub meta_auth: UncheckedAccount<'info>,
     pub token_2022_program: Program<'info, Token2022>,
     #[account(
         seeds = [b"protocol"],
@@ -71,184 +95,645 @@ impl<'info> CreatePlaceholder<'info> {
         &mut self,
         id: u64,
         uri: String,
+        default_frozen: bool,
+        extra_attributes: Vec<Attributes>,
+        symbol_override: Option<String>,
         bumps: CreatePlaceholderBumps,
-    ) -> Result<()> {
+    ) -> Result<PlaceholderAddresses> {
         /*
         
             Create Placeholder Nft Ix:
 
             Some security check:
-            - The admin_state.publickey must match the signing admin.
+            - The admin_state.publickey must match the signing admin, 
+            or the signer must be one of the creators delegated by the collection owner, 
+            or the collection owner itself if the collection allows it (owner_can_create).
+            - The collection account must be the [b"collection", collection.owner] PDA (defense in depth on top of the seeds).
 
             What these Instructions do:
-            - Creates a placeholder NFT.
+            - Creates a placeholder NFT, recording the signer (admin, delegated creator or owner) as created_by 
+            and emitting a PlaceholderCreated event.
+            - If default_frozen is set, every token account of the mint starts Frozen and the auth PDA 
+            (freeze authority) has to thaw it, the buy/airdrop only thaw to mint and freeze it right after.
+            - The id is an idempotency key: retrying with an existing id and the same uri, default_frozen, symbol 
+            & extra_attributes is a no-op, an existing id with different parameters errors.
+            - symbol_override (max MAX_SYMBOL_LEN bytes) replaces the collection symbol in the mint metadata, 
+            ex. for a collection released in several series.
+            - extra_attributes (ex. background, rarity) are written as additional metadata fields after the standard ones, 
+            at most MAX_EXTRA_ATTRIBUTES and MAX_EXTRA_ATTRIBUTES_LEN bytes in total.
+            - If the collection enforces unique uris, creates the UriMarker PDA [b"uri", collection, sha256(uri)], 
+            a second placeholder with the same uri fails with DuplicateUri.
+            - The metadata update authority is the collection meta_auth PDA [b"meta_auth", collection] 
+            if the collection isolates its metadata (collection_metadata_authority), the global auth PDA otherwise.
+            - In lazy mint mode (collection.lazy_mint) only the placeholder and the LazyMint PDA [b"lazy", placeholder] 
+            holding the mint parameters are created, the mint (same address) is created by the first buy/airdrop/claim, which closes the LazyMint PDA to its payer.
+            - Returns the placeholder and mint addresses with their canonical bumps as return data 
+            (also on an idempotent retry), clients chain the buy/airdrop without re-deriving them.
         */
 
-        require!(!self.protocol.locked, ProtocolError::ProtocolLocked);
-        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+        self.protocol.ensure_not_locked()?;
+        require_keys_eq!(
+            Pubkey::create_program_address(
+                &[b"collection", self.collection.owner.as_ref(), &[self.collection.bump]],
+                &crate::ID,
+            ).map_err(|_| ProtocolError::InvalidCollectionAccount)?,
+            self.collection.key(),
+            ProtocolError::InvalidCollectionAccount
+        );
+        require!(
+            self.admin_state.as_ref().is_some_and(|admin_state| admin_state.publickey == *self.admin.key)
+                || self.collection.creators.contains(self.admin.key)
+                || (self.collection.owner_can_create && self.collection.owner == *self.admin.key),
+            ProtocolError::UnauthorizedAdmin
+        );
         
-        if self.collection.total_supply > self.collection.max_supply{
-            return Err(BuyingError::SoldOut.into());
+        // init_if_needed loaded an existing placeholder, only an identical retry succeeds
+        if self.placeholder.collection != Pubkey::default() {
+            require!(
+                self.placeholder.id == id
+                    && self.placeholder.collection == self.collection.key()
+                    && if self.mint.data_is_empty() {
+                        self.lazy_mint_matches(
+                            &uri,
+                            default_frozen,
+                            symbol_override.as_deref().unwrap_or(&self.collection.symbol),
+                            &extra_attributes,
+                        )?
+                    } else {
+                        placeholder_mint_matches(
+                            &self.mint.to_account_info(),
+                            &uri,
+                            default_frozen,
+                            symbol_override.as_deref().unwrap_or(&self.collection.symbol),
+                            &extra_attributes,
+                        )?
+                    },
+                ProtocolError::PlaceholderAlreadyExists
+            );
+
+            return Ok(self.addresses(&bumps));
+        }
+
+        require!(!self.collection.is_sold_out(), BuyingError::SoldOut);
+        require!(
+            extra_attributes.len() <= MAX_EXTRA_ATTRIBUTES
+                && extra_attributes.iter().map(|attr| attr.key.len() + attr.value.len()).sum::<usize>() <= MAX_EXTRA_ATTRIBUTES_LEN,
+            ProtocolError::TooManyAttributes
+        );
+        require!(
+            !symbol_override.as_ref().is_some_and(|symbol| symbol.len() > MAX_SYMBOL_LEN),
+            ProtocolError::SymbolTooLong
+        );
+
+        if self.collection.enforce_unique_uri {
+            self.create_uri_marker(&uri, bumps.uri_marker)?;
         }
 
         self.placeholder.set_inner(
             Placeholder {
                 id, // 8
                 collection: self.collection.key(),  //32 bytes
-                reference: self.collection.reference.to_string(), // 4 + len
+                reference: self.collection.reference, // 32 bytes
                 name: self.collection.name.clone(), // 4 + len
-                price: self.collection.price, // 8
-                time_stamp: Clock::get()?.unix_timestamp // 8
+                price: self.collection.price, // 4
+                time_stamp: Clock::get()?.unix_timestamp, // 8
+                created_by: self.admin.key(), // 32
             }
         );
 
-        // Step 1: Initialize Account
-        let size = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
-            &[
-                ExtensionType::MintCloseAuthority,
-                ExtensionType::PermanentDelegate,
-                ExtensionType::MetadataPointer,
-            ],
-        ).unwrap();
-        let _count = self.collection.total_supply + 1;
-        let metadata = TokenMetadata {
-            update_authority: spl_pod::optional_keys::OptionalNonZeroPubkey::try_from(Some(self.auth.key())).unwrap(),
-            mint: self.mint.key(),
-            name: "Placeholder for".to_string() + &self.collection.name,
-            symbol: self.collection.symbol.clone(),
+        emit!(PlaceholderCreated {
+            collection: self.collection.key(),
+            placeholder: self.placeholder.key(),
+            id,
+            created_by: self.admin.key(),
+        });
+
+        if self.collection.lazy_mint {
+            self.create_lazy_mint(
+                LazyMint {
+                    uri,
+                    default_frozen,
+                    symbol_override,
+                    extra_attributes,
+                },
+                bumps.lazy_mint,
+            )?;
+
+            return Ok(self.addresses(&bumps));
+        }
+
+        initialize_placeholder_mint(
+            &self.admin.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.auth.to_account_info(),
+            &self.meta_auth.to_account_info(),
+            &self.token_2022_program.to_account_info(),
+            &Rent::get()?,
+            &self.collection,
+            self.placeholder.key(),
+            id,
             uri,
-            additional_metadata: vec![
-                ("id".to_string(), id.to_string()),
-                ("count".to_string(), _count.to_string()),
-                ("timestamp".to_string(), Clock::get()?.unix_timestamp.to_string()),
-                ("price".to_string(), self.collection.price.to_string()),
-                ("collection".to_string(), self.collection.name.to_string()),
-                ("collection key".to_string(), self.collection.key().to_string())
-            ]
+            default_frozen,
+            extra_attributes,
+            symbol_override,
+            bumps.mint,
+            bumps.auth,
+            bumps.meta_auth,
+        )?;
+
+        Ok(self.addresses(&bumps))
+    }
+
+    fn addresses(&self, bumps: &CreatePlaceholderBumps) -> PlaceholderAddresses {
+        PlaceholderAddresses {
+            placeholder: self.placeholder.key(),
+            placeholder_bump: bumps.placeholder,
+            mint: self.mint.key(),
+            mint_bump: bumps.mint,
+        }
+    }
+
+    // An existing marker means the uri is already taken in this collection
+    fn create_uri_marker(&self, uri: &str, uri_marker_bump: Option<u8>) -> Result<()> {
+        let (Some(uri_marker), Some(uri_marker_bump)) = (self.uri_marker.as_ref(), uri_marker_bump) else {
+            return Err(ProtocolError::UriMarkerMissing.into());
         };
+        require!(uri_marker.data_is_empty(), ProtocolError::DuplicateUri);
+
+        let collection_key = self.collection.key();
+        let uri_hash = hash(uri.as_bytes());
+        let seeds: &[&[u8]; 4] = &[
+            b"uri",
+            collection_key.as_ref(),
+            uri_hash.as_ref(),
+            &[uri_marker_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                &self.admin.key(),
+                &uri_marker.key(),
+                Rent::get()?.minimum_balance(UriMarker::INIT_SPACE),
+                UriMarker::INIT_SPACE as u64,
+                &crate::ID,
+            ),
+            &[
+                self.admin.to_account_info(),
+                uri_marker.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+            signer_seeds
+        )?;
+
+        UriMarker { placeholder: self.placeholder.key() }
+            .try_serialize(&mut &mut uri_marker.try_borrow_mut_data()?[..])
+    }
 
-        let extension_extra_space = metadata.tlv_size_of().unwrap();
-        let rent = &Rent::from_account_info(&self.rent.to_account_info())?;
-        let lamports = rent.minimum_balance(size + extension_extra_space);
+    // Lazy placeholders only store the mint parameters, sized to them
+    fn create_lazy_mint(&self, lazy_mint: LazyMint, lazy_mint_bump: Option<u8>) -> Result<()> {
+        let (Some(lazy_mint_account), Some(lazy_mint_bump)) = (self.lazy_mint.as_ref(), lazy_mint_bump) else {
+            return Err(ProtocolError::LazyMintMissing.into());
+        };
 
         let placeholder_key = self.placeholder.key();
         let seeds: &[&[u8]; 3] = &[
-            b"mint",
+            b"lazy",
             placeholder_key.as_ref(),
-            &[bumps.mint],
+            &[lazy_mint_bump],
         ];
         let signer_seeds = &[&seeds[..]];
 
+        let space = lazy_mint.space();
         invoke_signed(
-            &solana_program::system_instruction::create_account(
+            &system_instruction::create_account(
                 &self.admin.key(),
-                &self.mint.key(),
-                lamports,
-                (size).try_into().unwrap(),
-                &spl_token_2022::id(),
+                &lazy_mint_account.key(),
+                Rent::get()?.minimum_balance(space),
+                space as u64,
+                &crate::ID,
             ),
-            &vec![
+            &[
                 self.admin.to_account_info(),
-                self.mint.to_account_info(),
+                lazy_mint_account.to_account_info(),
+                self.system_program.to_account_info(),
             ],
             signer_seeds
         )?;
 
-        // Step 2: Initialize Extension needed: 
+        lazy_mint.try_serialize(&mut &mut lazy_mint_account.try_borrow_mut_data()?[..])
+    }
 
-        // 2.1: Permanent Delegate, 
-        invoke(
-            &initialize_permanent_delegate(
-                &self.token_2022_program.key(),
-                &self.mint.key(),
-                &self.auth.key(),
-            )?,
-            &vec![
-                self.mint.to_account_info(),
-            ],
-        )?;
-        
-        // 2.2: Close Mint Authority, 
+    // Retry of a lazy placeholder whose mint isn't created yet, compared with the stored mint parameters
+    fn lazy_mint_matches(
+        &self,
+        uri: &str,
+        default_frozen: bool,
+        symbol: &str,
+        extra_attributes: &[Attributes],
+    ) -> Result<bool> {
+        let Some(lazy_mint_account) = self.lazy_mint.as_ref() else {
+            return Err(ProtocolError::LazyMintMissing.into());
+        };
+        let lazy_mint = LazyMint::try_deserialize(&mut &lazy_mint_account.try_borrow_data()?[..])?;
+
+        Ok(lazy_mint.uri == uri
+            && lazy_mint.default_frozen == default_frozen
+            && lazy_mint.symbol_override.as_deref().unwrap_or(&self.collection.symbol) == symbol
+            && lazy_mint.extra_attributes == extra_attributes)
+    }
+}
+
+// Creates the mint of a lazy placeholder on its first delivery (buy, airdrop or claim) from the parameters 
+// stored at creation, the payer funds the mint rent and gets the LazyMint rent back (the account is closed once 
+// the mint exists). A no-op when the mint already exists.
+#[allow(clippy::too_many_arguments)]
+pub fn ensure_lazy_mint<'info>(
+    payer: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    auth: &AccountInfo<'info>,
+    meta_auth: &AccountInfo<'info>,
+    token_2022_program: &AccountInfo<'info>,
+    collection: &Account<'info, Collection>,
+    placeholder: &Account<'info, Placeholder>,
+    lazy_mint: Option<&Account<'info, LazyMint>>,
+    mint_bump: u8,
+    auth_bump: u8,
+    meta_auth_bump: u8,
+) -> Result<()> {
+    if !mint.data_is_empty() {
+        return Ok(());
+    }
+
+    let Some(lazy_mint) = lazy_mint else {
+        return Err(ProtocolError::LazyMintMissing.into());
+    };
+
+    initialize_placeholder_mint(
+        payer,
+        mint,
+        auth,
+        meta_auth,
+        token_2022_program,
+        &Rent::get()?,
+        collection,
+        placeholder.key(),
+        placeholder.id,
+        lazy_mint.uri.clone(),
+        lazy_mint.default_frozen,
+        lazy_mint.extra_attributes.clone(),
+        lazy_mint.symbol_override.clone(),
+        mint_bump,
+        auth_bump,
+        meta_auth_bump,
+    )?;
+
+    lazy_mint.close(payer.clone())
+}
+
+// Creates the placeholder mint (account, extensions, mint & metadata) for an already derived placeholder PDA,
+// shared between create_placeholder and mint_batch_for_collection
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_placeholder_mint<'info>(
+    admin: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    auth: &AccountInfo<'info>,
+    meta_auth: &AccountInfo<'info>,
+    token_2022_program: &AccountInfo<'info>,
+    rent: &Rent,
+    collection: &Account<'info, Collection>,
+    placeholder_key: Pubkey,
+    id: u64,
+    uri: String,
+    default_frozen: bool,
+    extra_attributes: Vec<Attributes>,
+    symbol_override: Option<String>,
+    mint_bump: u8,
+    auth_bump: u8,
+    meta_auth_bump: u8,
+) -> Result<()> {
+    // The metadata (and metadata pointer) authority is the collection meta_auth PDA when the collection 
+    // isolates its metadata, the global auth PDA (still mint authority) otherwise
+    let collection_key = collection.key();
+    let auth_seeds: &[&[u8]] = &[b"auth", &[auth_bump]];
+    let meta_auth_seeds: &[&[u8]] = &[b"meta_auth", collection_key.as_ref(), &[meta_auth_bump]];
+    let (update_authority, update_authority_seeds) = if collection.collection_metadata_authority {
+        (meta_auth, meta_auth_seeds)
+    } else {
+        (auth, auth_seeds)
+    };
+
+    // Step 1: Initialize Account
+    let mut extensions = vec![
+        ExtensionType::MintCloseAuthority,
+        ExtensionType::PermanentDelegate,
+        ExtensionType::MetadataPointer,
+    ];
+    if default_frozen {
+        extensions.push(ExtensionType::DefaultAccountState);
+    }
+    if collection.interest_rate.is_some() {
+        extensions.push(ExtensionType::InterestBearingConfig);
+    }
+    let size = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+        &extensions,
+    ).unwrap();
+    let _count = collection.total_supply + 1;
+    let mut metadata = TokenMetadata {
+        update_authority: spl_pod::optional_keys::OptionalNonZeroPubkey::try_from(Some(update_authority.key())).unwrap(),
+        mint: mint.key(),
+        name: "Placeholder for".to_string() + &collection.name,
+        symbol: symbol_override.unwrap_or_else(|| collection.symbol.clone()),
+        uri,
+        additional_metadata: vec![
+            ("id".to_string(), id.to_string()),
+            ("count".to_string(), _count.to_string()),
+            ("timestamp".to_string(), Clock::get()?.unix_timestamp.to_string()),
+            ("price".to_string(), collection.price.to_string()),
+            ("collection".to_string(), collection.name.to_string()),
+            ("collection key".to_string(), collection.key().to_string())
+        ]
+    };
+    metadata.additional_metadata.extend(extra_attributes.into_iter().map(|attr| (attr.key, attr.value)));
+
+    // The account is created with only the fixed extensions (size): InitializeMint rejects a mint with space left 
+    // for extensions that aren't initialized yet. The Token-2022 metadata instructions (initialize + update_field) 
+    // realloc the mint themselves to fit the TLV, they don't transfer lamports, so rent is funded upfront for the 
+    // full metadata (size + extension_extra_space) including the additional fields.
+    let extension_extra_space = metadata.tlv_size_of().unwrap();
+    let lamports = rent.minimum_balance(size + extension_extra_space);
+
+    let seeds: &[&[u8]; 3] = &[
+        b"mint",
+        placeholder_key.as_ref(),
+        &[mint_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    invoke_signed(
+        &solana_program::system_instruction::create_account(
+            &admin.key(),
+            &mint.key(),
+            lamports,
+            (size).try_into().unwrap(),
+            &spl_token_2022::id(),
+        ),
+        &vec![
+            admin.clone(),
+            mint.clone(),
+        ],
+        signer_seeds
+    )?;
+
+    // Step 2: Initialize Extension needed: 
+
+    // 2.1: Permanent Delegate, 
+    invoke(
+        &initialize_permanent_delegate(
+            &token_2022_program.key(),
+            &mint.key(),
+            &auth.key(),
+        )?,
+        &vec![
+            mint.clone(),
+        ],
+    )?;
+    
+    // 2.2: Close Mint Authority, 
+    invoke(
+        &initialize_mint_close_authority(
+            &token_2022_program.key(),
+            &mint.key(),
+            Some(&auth.key()),
+        )?,
+        &vec![
+            mint.clone(),
+        ],
+    )?;
+    
+    // 2.3: Metadata Pointer
+    invoke(
+        &initialize_metadata_pointer(
+            &token_2022_program.key(),
+            &mint.key(),
+            Some(update_authority.key()),
+            Some(mint.key()),
+        )?,
+        &vec![
+            mint.clone(),
+        ],
+    )?;
+
+    // 2.4: Default Account State (optional), requires a freeze authority on the mint
+    if default_frozen {
         invoke(
-            &initialize_mint_close_authority(
-                &self.token_2022_program.key(),
-                &self.mint.key(),
-                Some(&self.auth.key()),
+            &initialize_default_account_state(
+                &token_2022_program.key(),
+                &mint.key(),
+                &AccountState::Frozen,
             )?,
             &vec![
-                self.mint.to_account_info(),
+                mint.clone(),
             ],
         )?;
-        
-        // 2.3: Metadata Pointer
+    }
+
+    // 2.5: Interest Bearing Config (opt-in per collection)
+    if let Some(interest_rate) = collection.interest_rate {
         invoke(
-            &initialize_metadata_pointer(
-                &self.token_2022_program.key(),
-                &self.mint.key(),
-                Some(self.auth.key()),
-                Some(self.mint.key()),
+            &initialize_interest_bearing_config(
+                &token_2022_program.key(),
+                &mint.key(),
+                Some(auth.key()),
+                interest_rate,
             )?,
             &vec![
-                self.mint.to_account_info(),
+                mint.clone(),
             ],
         )?;
+    }
 
-        // Step 3: Initialize Mint & Metadata Account
-        invoke_signed(
-            &initialize_mint2(
-                &self.token_2022_program.key(),
-                &self.mint.key(),
-                &self.auth.key(),
-                None,
-                0,
-            )?,
-            &vec![
-                self.mint.to_account_info(),
-            ],
-            signer_seeds
-        )?;
+    // Step 3: Initialize Mint & Metadata Account
+    let freeze_authority = auth.key();
+    invoke_signed(
+        &initialize_mint2(
+            &token_2022_program.key(),
+            &mint.key(),
+            &auth.key(),
+            if default_frozen { Some(&freeze_authority) } else { None },
+            0,
+        )?,
+        &vec![
+            mint.clone(),
+        ],
+        signer_seeds
+    )?;
 
-        let seeds: &[&[u8]; 2] = &[
-            b"auth",
-            &[bumps.auth],
-        ];
-        let signer_seeds = &[&seeds[..]];
+    assert_mint_authority(mint, &auth.key(), if default_frozen { Some(freeze_authority) } else { None })?;
+
+    // The auth PDA signs as mint authority, the update authority is only recorded
+    invoke_signed(
+        &initialize_metadata_account(
+            &token_2022_program.key(),
+            &mint.key(),
+            &update_authority.key(),
+            &mint.key(),
+            &auth.key(),
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+        ),
+        &vec![
+            mint.clone(),
+            update_authority.clone(),
+            auth.clone(),
+        ],
+        &[auth_seeds]
+    )?;
 
+    for (field, value) in metadata.additional_metadata.into_iter() {
         invoke_signed(
-            &initialize_metadata_account(
-                &self.token_2022_program.key(),
-                &self.mint.key(),
-                &self.auth.key(),
-                &self.mint.key(),
-                &self.auth.key(),
-                metadata.name,
-                metadata.symbol,
-                metadata.uri,
+            &update_metadata_account(
+                &token_2022_program.key(),
+                &mint.key(),
+                &update_authority.key(),
+                Field::Key(field),
+                value,
             ),
             &vec![
-                self.mint.to_account_info(),
-                self.auth.to_account_info(),
+                mint.clone(),
+                update_authority.clone(),
             ],
-            signer_seeds
+            &[update_authority_seeds]
         )?;
+    }
 
-        for (field, value) in metadata.additional_metadata.into_iter() {
-            invoke_signed(
-                &update_metadata_account(
-                    &self.token_2022_program.key(),
-                    &self.mint.key(),
-                    &self.auth.key(),
-                    Field::Key(field),
-                    value,
-                ),
-                &vec![
-                    self.mint.to_account_info(),
-                    self.auth.to_account_info(),
-                ],
-                signer_seeds
-            )?;
-        }
+    assert_metadata_authority(mint, &update_authority.key())?;
 
-       Ok(())
-    }
+   Ok(())
+}
+
+// id, count, timestamp, price, collection & collection key, written before the extra attributes
+const STANDARD_METADATA_FIELDS: usize = 6;
+
+// Compares an already created placeholder mint with the parameters of a create retry
+fn placeholder_mint_matches(
+    mint: &AccountInfo,
+    uri: &str,
+    default_frozen: bool,
+    symbol: &str,
+    extra_attributes: &[Attributes],
+) -> Result<bool> {
+    assert_inline_metadata(mint)?;
+
+    let mint_data = mint.data.borrow();
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let metadata = mint_state.get_variable_len_extension::<TokenMetadata>()?;
+
+    Ok(metadata.uri == uri 
+        && mint_state.base.freeze_authority.is_some() == default_frozen
+        && metadata.symbol == symbol
+        // the extra attributes are the additional fields written after the standard ones
+        && metadata.additional_metadata
+            .iter()
+            .skip(STANDARD_METADATA_FIELDS)
+            .map(|(key, value)| (key, value))
+            .eq(extra_attributes.iter().map(|attr| (&attr.key, &attr.value))))
+}
+
+// Every mint is created with its metadata pointer on itself, instructions reading or writing the inline 
+// metadata of an existing mint check it first instead of assuming it
+pub fn assert_inline_metadata(
+    mint: &AccountInfo,
+) -> Result<()> {
+    let mint_data = mint.data.borrow();
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let metadata_pointer = mint_state.get_extension::<MetadataPointer>()?;
+
+    require!(
+        Option::<Pubkey>::from(metadata_pointer.metadata_address) == Some(mint.key()),
+        ProtocolError::MetadataPointerMismatch
+    );
+
+    Ok(())
+}
+
+// Invariant checks between the create CPIs: the mint must be initialized with the auth PDA as mint (and freeze) 
+// authority before the metadata is written, so a reordered sequence fails here instead of creating a broken mint
+pub fn assert_mint_authority(
+    mint: &AccountInfo,
+    auth: &Pubkey,
+    freeze_authority: Option<Pubkey>,
+) -> Result<()> {
+    let mint_data = mint.data.borrow();
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+
+    require!(mint_state.base.is_initialized, ProtocolError::MintAuthorityMismatch);
+    require!(mint_state.base.mint_authority == COption::Some(*auth), ProtocolError::MintAuthorityMismatch);
+    require!(mint_state.base.freeze_authority == COption::from(freeze_authority), ProtocolError::MintAuthorityMismatch);
+
+    Ok(())
+}
+
+// The metadata TLV must exist on the mint with the auth (or collection meta_auth) PDA as update authority 
+// before anything is minted
+pub fn assert_metadata_authority(
+    mint: &AccountInfo,
+    auth: &Pubkey,
+) -> Result<()> {
+    require!(
+        metadata_update_authority(mint)? == Some(*auth),
+        ProtocolError::MetadataAuthorityMismatch
+    );
+
+    Ok(())
+}
+
+// Current update authority recorded in the mint metadata TLV
+pub fn metadata_update_authority(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    let mint_data = mint.data.borrow();
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let metadata = mint_state.get_variable_len_extension::<TokenMetadata>()?;
+
+    Ok(Option::<Pubkey>::from(metadata.update_authority))
+}
+
+// Signs an update_field on a placeholder/NFT metadata with whichever PDA is its update authority: 
+// the collection meta_auth for mints created while the collection isolated its metadata, the global auth otherwise
+#[allow(clippy::too_many_arguments)]
+pub fn update_metadata_field<'info>(
+    token_2022_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    auth: &AccountInfo<'info>,
+    meta_auth: &AccountInfo<'info>,
+    collection_key: Pubkey,
+    field: String,
+    value: String,
+    auth_bump: u8,
+    meta_auth_bump: u8,
+) -> Result<()> {
+    let auth_seeds: &[&[u8]] = &[b"auth", &[auth_bump]];
+    let meta_auth_seeds: &[&[u8]] = &[b"meta_auth", collection_key.as_ref(), &[meta_auth_bump]];
+    let (update_authority, update_authority_seeds) = if metadata_update_authority(mint)? == Some(meta_auth.key()) {
+        (meta_auth, meta_auth_seeds)
+    } else {
+        (auth, auth_seeds)
+    };
+
+    invoke_signed(
+        &update_metadata_account(
+            &token_2022_program.key(),
+            &mint.key(),
+            &update_authority.key(),
+            Field::Key(field),
+            value,
+        ),
+        &vec![
+            mint.clone(),
+            update_authority.clone(),
+        ],
+        &[update_authority_seeds]
+    )?;
+
+    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,171 @@
+use {
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token_2022::Token2022,
+        associated_token::AssociatedToken,
+    },
+};
+use crate::{
+    constant::{self, PLACEHOLDER_MINT_AMOUNT, FEATURE_DIRECT_AIRDROP}, errors::{BuyingError, ProtocolError}, state::{Collection, Placeholder, Protocol, LazyMint}
+};
+use super::{ensure_lazy_mint, mint_placeholder, pay_mint_fee, observe_phase, advance_next_placeholder};
+
+#[derive(Accounts)]
+pub struct DirectAirdrop<'info> {
+    /// CHECK: Buyer is being added by the admin
+    #[account(mut)]
+    pub buyer: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub collection: Account<'info, Collection>,
+    #[account(
+        mut,
+        seeds = [
+            buyer.key().as_ref(),
+            token_2022_program.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        seeds::program = associated_token_program.key(),
+        bump
+    )]
+    /// CHECK
+    pub buyer_mint_ata: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"placeholder", placeholder.collection.key().as_ref(), collection.placeholder_seed(placeholder.id).as_ref()],
+        bump,
+    )] 
+    pub placeholder: Account<'info, Placeholder>,
+    #[account(
+        mut,
+        seeds = [b"mint", placeholder.key().as_ref()],
+        bump
+    )]
+    /// CHECK
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"auth"],
+        bump
+    )]
+    /// CHECK:
+    pub auth: UncheckedAccount<'info>,
+    /// CHECK: metadata update authority of the collection mints when collection_metadata_authority is set
+    #[account(
+        seeds = [b"meta_auth", collection.key().as_ref()],
+        bump
+    )]
+    pub meta_auth: UncheckedAccount<'info>,
+    // Only needed to deliver a lazy placeholder whose mint isn't created yet (collection.lazy_mint)
+    #[account(
+        mut,
+        seeds = [b"lazy", placeholder.key().as_ref()],
+        bump
+    )]
+    pub lazy_mint: Option<Account<'info, LazyMint>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    #[account(
+        mut,
+        address = protocol.treasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+    // Only needed when the collection has an airdrop_rent_payer, it funds the buyer ATA instead of the payer
+    #[account(mut)]
+    pub rent_payer: Option<Signer<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DirectAirdrop<'info> {
+    pub fn airdrop(
+        &mut self,
+        bumps: DirectAirdropBumps,
+    ) -> Result<()> {
+
+        /*
+        
+            Direct Airdrop Ix:
+
+            Some security check:
+            - The payer must be the admin wallet signing the transaction, no ED25519 instruction is needed. 
+            Off-chain authorized airdrops keep going through airdrop_placeholder.
+            - The buyer can't be the admin paying for the airdrop.
+            - The placeholder must belong to the collection.
+
+            What these Instructions do:
+            - Mints the placeholder NFT to the buyer without paying the mint price, the admin pays the protocol 
+            mint_fee_lamports (if any) and the ATA rent, unless the collection has an airdrop_rent_payer which must 
+            then co-sign as rent_payer and funds the ATA (RentPayerMismatch otherwise), like airdrop_placeholder.
+            - Lazy placeholders (collection.lazy_mint) get their mint created first from the LazyMint PDA, the admin payer funds the mint rent. 
+            This adds the create_placeholder cost (~CREATE_PLACEHOLDER_CU_ESTIMATE, 400k CU) to the instruction, 
+            clients should request ~600k compute units with a ComputeBudgetProgram instruction.
+            - Increase the total_supply on the collection (total minted nfts).
+            - require_memo_on_transfer is not applied: enabling MemoTransfer on the buyer ATA needs the buyer 
+            signature and the buyer doesn't sign an airdrop, the airdropped ATA stays without the memo requirement.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.protocol.is_feature_enabled(FEATURE_DIRECT_AIRDROP), ProtocolError::FeatureDisabled);
+        require!(self.payer.key() == constant::admin_wallet::id(), ProtocolError::UnauthorizedAdmin);
+        require!(self.buyer.key() != self.payer.key(), ProtocolError::SelfAirdrop);
+        require!(self.placeholder.collection == self.collection.key(), ProtocolError::CollectionMismatch);
+        require!(!self.collection.is_sold_out(), BuyingError::SoldOut);
+        require!(!self.collection.is_paused(Clock::get()?.unix_timestamp), BuyingError::CollectionPaused);
+
+        let ata_rent_payer = match self.collection.airdrop_rent_payer {
+            None => self.payer.to_account_info(),
+            Some(airdrop_rent_payer) => {
+                let Some(rent_payer) = self.rent_payer.as_ref() else {
+                    return Err(ProtocolError::RentPayerMismatch.into());
+                };
+                require_keys_eq!(rent_payer.key(), airdrop_rent_payer, ProtocolError::RentPayerMismatch);
+                rent_payer.to_account_info()
+            }
+        };
+
+        pay_mint_fee(
+            &self.payer.to_account_info(),
+            &self.treasury.to_account_info(),
+            &self.system_program.to_account_info(),
+            self.protocol.mint_fee_lamports,
+        )?;
+
+        ensure_lazy_mint(
+            &self.payer.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.auth.to_account_info(),
+            &self.meta_auth.to_account_info(),
+            &self.token_2022_program.to_account_info(),
+            &self.collection,
+            &self.placeholder,
+            self.lazy_mint.as_ref(),
+            bumps.mint,
+            bumps.auth,
+            bumps.meta_auth,
+        )?;
+
+        mint_placeholder(
+            &ata_rent_payer,
+            &self.buyer.to_account_info(),
+            &self.buyer_mint_ata.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.auth.to_account_info(),
+            &self.token_2022_program.to_account_info(),
+            &self.system_program.to_account_info(),
+            PLACEHOLDER_MINT_AMOUNT,
+            bumps.auth,
+        )?;
+
+        observe_phase(&mut self.collection)?;
+        advance_next_placeholder(&mut self.collection, self.placeholder.id);
+
+        self.collection.total_supply += 1;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct FreezeCollectionMetadata<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FreezeCollectionMetadata<'info> {
+    pub fn freeze(
+        &mut self,
+    ) -> Result<()> {
+
+        /*
+        
+            Freeze Collection Metadata Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+
+            What these Instructions do:
+            - Sets frozen_metadata on the collection, this is one-way and can never be un-set.
+            - Any instruction mutating the collection metadata must reject with ProtocolError::MetadataFrozen once set.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+        require!(!self.collection.frozen_metadata, ProtocolError::MetadataFrozen);
+
+        self.collection.frozen_metadata = true;
+
+        Ok(())
+    }
+}
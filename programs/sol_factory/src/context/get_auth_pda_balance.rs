@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{Protocol, SweepableBalances};
+
+#[derive(Accounts)]
+pub struct GetAuthPdaBalance<'info> {
+    #[account(
+        seeds = [b"auth"],
+        bump
+    )]
+    /// CHECK: only the lamports are read
+    pub auth: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    #[account(address = protocol.treasury)]
+    pub treasury: SystemAccount<'info>,
+}
+
+impl<'info> GetAuthPdaBalance<'info> {
+    pub fn get(
+        &self,
+    ) -> Result<SweepableBalances> {
+
+        /*
+        
+            Get Auth PDA Balance Ix:
+
+            What these Instructions do:
+            - Read only, returns the lamports of the auth PDA and of the protocol treasury above their 
+            rent exemption minimum as return data, the canonical readout of what operators can sweep.
+        */
+
+        let rent = Rent::get()?;
+        let sweepable = |account: &AccountInfo| account.lamports().saturating_sub(rent.minimum_balance(account.data_len()));
+
+        Ok(SweepableBalances {
+            auth: sweepable(&self.auth.to_account_info()),
+            treasury: sweepable(&self.treasury.to_account_info()),
+        })
+    }
+}
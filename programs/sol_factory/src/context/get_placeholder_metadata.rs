@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::{Placeholder, PlaceholderMetadata};
+
+#[derive(Accounts)]
+pub struct GetPlaceholderMetadata<'info> {
+    pub placeholder: Account<'info, Placeholder>,
+}
+
+impl<'info> GetPlaceholderMetadata<'info> {
+    pub fn get(
+        &self,
+    ) -> Result<PlaceholderMetadata> {
+
+        /*
+        
+            Get Placeholder Metadata Ix:
+
+            What these Instructions do:
+            - Read only, returns the placeholder id, collection, price and timestamp as return data 
+            so clients don't need to parse the Token-2022 metadata TLV of the mint.
+        */
+
+        Ok(PlaceholderMetadata {
+            id: self.placeholder.id,
+            collection: self.placeholder.collection,
+            price: self.placeholder.price,
+            time_stamp: self.placeholder.time_stamp,
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, CollectionSupply};
+
+#[derive(Accounts)]
+pub struct GetRemainingSupply<'info> {
+    pub collection: Account<'info, Collection>,
+}
+
+impl<'info> GetRemainingSupply<'info> {
+    pub fn get(
+        &self,
+    ) -> Result<CollectionSupply> {
+
+        /*
+        
+            Get Remaining Supply Ix:
+
+            What these Instructions do:
+            - Read only, returns how many placeholders are left (max_supply - total_supply), whether 
+            the public sale is currently open and the effective pause_reason (0 outside the pause window) 
+            as return data so clients don't need to fetch the collection.
+        */
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        Ok(CollectionSupply {
+            remaining: self.collection.max_supply.saturating_sub(self.collection.total_supply),
+            public_sale_active: self.collection.is_public_sale_active(current_time),
+            pause_reason: self.collection.effective_pause_reason(current_time),
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Protocol, CollectionRegistry},
+    constant::multisig_wallet,
+    errors::SetupError,
+    events::{ProtocolLockChanged, ProtocolHalted},
+};
+
+#[derive(Accounts)]
+pub struct HaltAndSnapshot<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    // Absent until the first collection is created with the registry
+    #[account(
+        seeds = [b"registry"],
+        bump,
+    )]
+    pub registry: Option<Account<'info, CollectionRegistry>>,
+}
+
+impl<'info> HaltAndSnapshot<'info> {
+    pub fn halt(
+        &mut self,
+        reason_code: u8,
+    ) -> Result<()> {
+
+        /*
+        
+            Halt And Snapshot Ix:
+
+            Some security check:
+            - Only the multisig (highest security clearance of the protocol) can halt.
+
+            What these Instructions do:
+            - Incident response freeze point: locks the protocol and stamps locked_at (ProtocolLockChanged), 
+            also when it was already locked.
+            - Emits a ProtocolHalted event with the off-chain defined reason_code and a snapshot of the 
+            aggregate counters: the registry total_collections (0 without a registry). 
+            There is no protocol wide mint counter, the per collection total_supply stays on the collections.
+        */
+
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        self.protocol.locked = true;
+        self.protocol.locked_at = timestamp;
+
+        emit!(ProtocolLockChanged {
+            locked: true,
+            timestamp,
+        });
+
+        emit!(ProtocolHalted {
+            timestamp,
+            reason_code,
+            total_collections: self.registry.as_ref().map_or(0, |registry| registry.total_collections),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        program::invoke,
+        system_instruction,
+    },
+};
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::TokenAccount,
+};
+use crate::state::{Collection, Protocol, AiNft};
+use crate::errors::{BuyingError, ProtocolError};
+use crate::constant::{HOLDER_EDITABLE_FIELDS, MAX_HOLDER_FIELD_VALUE_LEN};
+use super::{assert_inline_metadata, update_metadata_field};
+
+#[derive(Accounts)]
+pub struct HolderUpdateField<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    #[account(
+        seeds = [b"collection", collection.owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"ainft", collection.key().as_ref(), nft.id.to_le_bytes().as_ref()],
+        bump,
+    )] 
+    pub nft: Account<'info, AiNft>,
+    /// CHECK: this is fine since the metadata update is checked by the Token-2022 program.
+    #[account(
+        mut,
+        seeds = [b"mint", nft.key().as_ref()],
+        bump
+    )]
+    pub mint: UncheckedAccount<'info>,
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK:
+    #[account(
+        seeds = [b"auth"],
+        bump
+    )]
+    pub auth: UncheckedAccount<'info>,
+    /// CHECK: metadata update authority of the mints created while collection_metadata_authority was set
+    #[account(
+        seeds = [b"meta_auth", collection.key().as_ref()],
+        bump
+    )]
+    pub meta_auth: UncheckedAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> HolderUpdateField<'info> {
+    pub fn update(
+        &mut self,
+        field: String,
+        value: String,
+        bumps: HolderUpdateFieldBumps,
+    ) -> Result<()> {
+
+        /*
+        
+            Holder Update Field Ix:
+
+            Some security check:
+            - The collection must allow holder updates (collection.enable_holder_updates).
+            - The collection metadata must not be frozen (frozen_metadata), holder fields freeze with the rest.
+            - The signer must hold the NFT: a token account of the mint owned by the signer with a balance of at least 1.
+            - Only the HOLDER_EDITABLE_FIELDS keys can be written, the core fields (id, collection key, ...) 
+            stay admin only, the value is capped to MAX_HOLDER_FIELD_VALUE_LEN bytes.
+            - The mint metadata pointer must point to the mint itself (inline metadata).
+
+            What these Instructions do:
+            - Writes the field in the NFT Token-2022 metadata, signed by the update authority PDA 
+            (collection meta_auth or global auth) on the holder behalf.
+            - The metadata realloc is funded by the holder (top up to rent exemption).
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.collection.enable_holder_updates, ProtocolError::HolderUpdatesDisabled);
+        require!(!self.collection.frozen_metadata, ProtocolError::MetadataFrozen);
+        require!(HOLDER_EDITABLE_FIELDS.contains(&field.as_str()), ProtocolError::FieldNotHolderEditable);
+        require!(value.len() <= MAX_HOLDER_FIELD_VALUE_LEN, ProtocolError::FieldNotHolderEditable);
+
+        require_keys_eq!(self.holder_token_account.mint, self.mint.key(), BuyingError::NotHolder);
+        require_keys_eq!(self.holder_token_account.owner, self.holder.key(), BuyingError::NotHolder);
+        require!(self.holder_token_account.amount >= 1, BuyingError::NotHolder);
+
+        assert_inline_metadata(&self.mint.to_account_info())?;
+
+        update_metadata_field(
+            &self.token_2022_program.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.auth.to_account_info(),
+            &self.meta_auth.to_account_info(),
+            self.collection.key(),
+            field,
+            value,
+            bumps.auth,
+            bumps.meta_auth,
+        )?;
+
+        // update_field reallocs the mint without transferring lamports, top it up to stay rent exempt
+        let rent_exempt = Rent::get()?.minimum_balance(self.mint.data_len());
+        let missing_lamports = rent_exempt.saturating_sub(self.mint.lamports());
+        if missing_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    &self.holder.key(),
+                    &self.mint.key(),
+                    missing_lamports,
+                ),
+                &[
+                    self.holder.to_account_info(),
+                    self.mint.to_account_info(),
+                    self.system_program.to_account_info(),
+                ],
+            )?;
+        }
+
+        Ok(())
+    }
+}
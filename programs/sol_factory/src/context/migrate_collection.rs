@@ -0,0 +1,127 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        program::invoke,
+        system_instruction,
+    },
+    Discriminator,
+};
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+use crate::constant::COLLECTION_VERSION;
+
+#[derive(Accounts)]
+pub struct MigrateCollection<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is migrating on owner behalf
+    pub owner: AccountInfo<'info>,
+    /// CHECK: an old layout can't be deserialized as Account<Collection>, the owner, 
+    /// PDA and discriminator are checked here and in the instruction.
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump,
+    )] 
+    pub collection: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateCollection<'info> {
+    pub fn migrate(
+        &mut self,
+        bumps: MigrateCollectionBumps,
+    ) -> Result<()> {
+
+        /*
+        
+            Migrate Collection Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+            - The account must be a Collection PDA of the owner (program owned + discriminator).
+            - Only collections below COLLECTION_VERSION can be migrated.
+
+            What these Instructions do:
+            - Fields are only ever appended to Collection, so an old account is its old layout followed by nothing: 
+            the account is grown zero filled, zeros deserialize as the defaults of the new fields 
+            (None, false, 0, empty Vec, SalePhase::NotStarted, SaleType::Fixed).
+            - Reallocs the account to collection.space(), bumps the version and tops up the rent from the admin.
+
+            Versions:
+            - 0: every collection created before the version field (no version byte).
+            - 1: adds the version byte, a migrated 0 gets every field added since the original layout 
+            (salt to launch_verified) zero initialized, a zero salt keeps its original placeholder derivation.
+            - 2: adds owner_can_create (false).
+            - 3: adds description (empty).
+            - 4: adds next_placeholder_id (0).
+            - 5: adds bump, set from the canonical bump of the collection PDA.
+            - 6: adds sale_type (SaleType::Fixed) and the Dutch auction prices (0).
+            - 7: adds enforce_unique_uri (false).
+            - 8: adds created_at, 0 for migrated collections (the creation time wasn't recorded).
+            - 9: adds enable_holder_updates (false).
+            - 10: adds whitelist_supply_cap (0, no cap) and whitelist_minted (0).
+            - 11: adds pause_reason (0, none).
+            - 12: adds airdrop_rent_payer (None, the admin pays).
+            - 13: adds collection_metadata_authority (false, the global auth PDA).
+            - 14: adds require_memo_on_transfer (false).
+            - 15: adds lazy_mint (false).
+            - 16: adds signature_whitelist (false).
+            - 17: adds whitelist_end_time (0, no whitelist window).
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+
+        let collection_info = self.collection.to_account_info();
+        {
+            let data = collection_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == Collection::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+        }
+
+        // Grow zero filled so the old layout deserializes with the new fields defaulted
+        collection_info.realloc(collection_info.data_len() + Collection::INIT_SPACE, true)?;
+
+        let mut collection = Collection::try_deserialize(&mut &collection_info.try_borrow_data()?[..])?;
+        require!(collection.version < COLLECTION_VERSION, ProtocolError::CollectionAlreadyMigrated);
+
+        collection.version = COLLECTION_VERSION;
+        collection.bump = bumps.collection;
+
+        let space = collection.space();
+        collection_info.realloc(space, false)?;
+        collection.try_serialize(&mut &mut collection_info.try_borrow_mut_data()?[..])?;
+
+        let missing_lamports = Rent::get()?.minimum_balance(space).saturating_sub(collection_info.lamports());
+        if missing_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    &self.admin.key(),
+                    &collection_info.key(),
+                    missing_lamports,
+                ),
+                &[
+                    self.admin.to_account_info(),
+                    collection_info.clone(),
+                    self.system_program.to_account_info(),
+                ],
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        program::invoke,
+        system_instruction,
+    },
+    Discriminator,
+};
+use crate::state::Protocol;
+use crate::errors::{SetupError, ProtocolError};
+use crate::constant::{multisig_wallet, admin_wallet};
+
+#[derive(Accounts)]
+pub struct MigrateProtocol<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: an old layout can't be deserialized as Account<Protocol>, the owner, 
+    /// PDA and discriminator are checked here and in the instruction.
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateProtocol<'info> {
+    pub fn migrate(
+        &mut self,
+        treasury: Pubkey,
+    ) -> Result<()> {
+
+        /*
+        
+            Migrate Protocol Ix:
+
+            Some security check:
+            - Only the multisig (highest security clearance of the protocol) can migrate.
+            - The account must be the protocol PDA (program owned + discriminator), smaller than Protocol::INIT_SPACE.
+
+            What these Instructions do:
+            - Fields are only ever appended to Protocol, so a protocol deployed with an older layout is that layout 
+            followed by nothing: the account is grown zero filled to Protocol::INIT_SPACE and the missing fields 
+            deserialize as zeros, the rent is topped up by the multisig.
+            - Zeros that would break the protocol get the initialize_protocol defaults: the treasury argument 
+            if no treasury is set, every feature enabled if feature_flags is 0, the admin_wallet as airdrop_signer. 
+            mint_fee_lamports, min_public_price and locked_at stay 0, the lock state is kept.
+        */
+
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+
+        let protocol_info = self.protocol.to_account_info();
+        {
+            let data = protocol_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == Protocol::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+        }
+        require!(protocol_info.data_len() < Protocol::INIT_SPACE, ProtocolError::ProtocolAlreadyMigrated);
+
+        // Grow zero filled so the old layout deserializes with the new fields defaulted
+        protocol_info.realloc(Protocol::INIT_SPACE, true)?;
+
+        let mut protocol = Protocol::try_deserialize(&mut &protocol_info.try_borrow_data()?[..])?;
+
+        if protocol.treasury == Pubkey::default() {
+            protocol.treasury = treasury;
+        }
+        if protocol.feature_flags == 0 {
+            protocol.feature_flags = u64::MAX;
+        }
+        if protocol.airdrop_signer == Pubkey::default() {
+            protocol.airdrop_signer = admin_wallet::id();
+        }
+
+        protocol.try_serialize(&mut &mut protocol_info.try_borrow_mut_data()?[..])?;
+
+        let missing_lamports = Rent::get()?.minimum_balance(Protocol::INIT_SPACE).saturating_sub(protocol_info.lamports());
+        if missing_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    &self.admin.key(),
+                    &protocol_info.key(),
+                    missing_lamports,
+                ),
+                &[
+                    self.admin.to_account_info(),
+                    protocol_info.clone(),
+                    self.system_program.to_account_info(),
+                ],
+            )?;
+        }
+
+        Ok(())
+    }
+}
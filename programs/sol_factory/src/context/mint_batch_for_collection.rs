@@ -0,0 +1,224 @@
+use anchor_lang::{
+    solana_program::{
+        program::invoke_signed,
+        system_instruction,
+    },
+    prelude::*
+};
+use anchor_spl::token_2022::Token2022;
+use crate::{
+    constant::{MAX_PLACEHOLDER_BATCH, FEATURE_BATCH_MINT},
+    state::{Protocol, Collection, Admin, Placeholder},
+    errors::{BuyingError, ProtocolError},
+    events::PlaceholderCreated,
+};
+use super::initialize_placeholder_mint;
+
+#[derive(Accounts)]
+pub struct MintBatchForCollection<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"collection", collection.owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    /// CHECK:
+    #[account(
+        seeds = [b"auth"],
+        bump
+    )]
+    pub auth: UncheckedAccount<'info>,
+    /// CHECK: metadata update authority of the collection mints when collection_metadata_authority is set
+    #[account(
+        seeds = [b"meta_auth", collection.key().as_ref()],
+        bump
+    )]
+    pub meta_auth: UncheckedAccount<'info>,
+    pub token_2022_program: Program<'info, Token2022>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MintBatchForCollection<'info> {
+    pub fn create_batch(
+        &mut self,
+        ids: Vec<u64>,
+        uri: String,
+        default_frozen: bool,
+        bumps: MintBatchForCollectionBumps,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+
+        /*
+        
+            Mint Batch For Collection Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+            - The batch can hold at most MAX_PLACEHOLDER_BATCH placeholders, clients should request the max 
+            compute units (1.4M) with a ComputeBudgetProgram instruction.
+            - Every placeholder/mint pair in the remaining accounts must match the PDAs derived from its id.
+            - A repeated id is rejected before any CPI, instead of failing on the second create_account.
+            - The uri is shared by every id of the batch, collections enforcing unique uris must use create_placeholder 
+            (UniqueUriBatchUnsupported).
+            - Lazy mint collections must use create_placeholder (the batch always creates the mints).
+
+            What these Instructions do:
+            - Creates a placeholder NFT for each id, same as create_placeholder. 
+            - remaining_accounts: [placeholder_0, mint_0, placeholder_1, mint_1, ...] in the order of the ids.
+            - Any failing item fails the whole transaction, so nothing of the batch is created, 
+            the index and id of the failing item are logged (simulate_mint_batch checks every item upfront).
+        */
+
+        validate_batch(
+            &self.protocol,
+            &self.collection,
+            &self.admin_state,
+            self.admin.key,
+            &ids,
+            remaining_accounts.len(),
+        )?;
+        require!(
+            ids.iter().enumerate().all(|(i, id)| !ids[..i].contains(id)),
+            ProtocolError::DuplicateInBatch
+        );
+
+        let rent = Rent::get()?;
+        let collection_key = self.collection.key();
+        let space = Placeholder::space(&self.collection);
+
+        for (index, (&id, accounts)) in ids.iter().zip(remaining_accounts.chunks(2)).enumerate() {
+            let placeholder = &accounts[0];
+            let mint = &accounts[1];
+
+            // The whole batch reverts on failure, log which item it was
+            let log_failure = |err: Error| {
+                msg!("Batch item {} (id {}) failed", index, id);
+                err
+            };
+
+            let (placeholder_bump, mint_bump) = validate_batch_item(&self.collection, &ids[..index], id, placeholder, mint)
+                .map_err(log_failure)?;
+            let placeholder_seed = self.collection.placeholder_seed(id);
+            let placeholder_key = placeholder.key();
+
+            // Create the placeholder account, what the init constraint does in create_placeholder
+            invoke_signed(
+                &system_instruction::create_account(
+                    &self.admin.key(),
+                    &placeholder_key,
+                    rent.minimum_balance(space),
+                    space as u64,
+                    &crate::ID,
+                ),
+                &[
+                    self.admin.to_account_info(),
+                    placeholder.clone(),
+                ],
+                &[&[b"placeholder", collection_key.as_ref(), placeholder_seed.as_ref(), &[placeholder_bump]]],
+            ).map_err(|err| log_failure(err.into()))?;
+
+            Placeholder {
+                id,
+                collection: collection_key,
+                reference: self.collection.reference,
+                name: self.collection.name.clone(),
+                price: self.collection.price,
+                time_stamp: Clock::get()?.unix_timestamp,
+                created_by: self.admin.key(),
+            }.try_serialize(&mut &mut placeholder.try_borrow_mut_data()?[..])?;
+
+            emit!(PlaceholderCreated {
+                collection: collection_key,
+                placeholder: placeholder_key,
+                id,
+                created_by: self.admin.key(),
+            });
+
+            initialize_placeholder_mint(
+                &self.admin.to_account_info(),
+                mint,
+                &self.auth.to_account_info(),
+                &self.meta_auth.to_account_info(),
+                &self.token_2022_program.to_account_info(),
+                &rent,
+                &self.collection,
+                placeholder_key,
+                id,
+                uri.clone(),
+                default_frozen,
+                Vec::new(),
+                None,
+                mint_bump,
+                bumps.auth,
+                bumps.meta_auth,
+            ).map_err(log_failure)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Batch level checks shared by mint_batch_for_collection and simulate_mint_batch, 
+// so a simulation fails the same way as the batch when the collection can't take one
+pub fn validate_batch(
+    protocol: &Protocol,
+    collection: &Collection,
+    admin_state: &Admin,
+    admin: &Pubkey,
+    ids: &[u64],
+    remaining_accounts_len: usize,
+) -> Result<()> {
+    protocol.ensure_not_locked()?;
+    require!(protocol.is_feature_enabled(FEATURE_BATCH_MINT), ProtocolError::FeatureDisabled);
+    require!(admin_state.publickey == *admin, ProtocolError::UnauthorizedAdmin);
+    require!(!ids.is_empty() && ids.len() <= MAX_PLACEHOLDER_BATCH, ProtocolError::InvalidBatchSize);
+    require!(remaining_accounts_len == ids.len() * 2, ProtocolError::InvalidBatchSize);
+    require!(!collection.enforce_unique_uri, ProtocolError::UniqueUriBatchUnsupported);
+    require!(!collection.lazy_mint, ProtocolError::LazyMintUnsupported);
+    require!(!collection.is_sold_out(), BuyingError::SoldOut);
+
+    Ok(())
+}
+
+// Per item checks shared by mint_batch_for_collection and simulate_mint_batch, 
+// returns the canonical placeholder & mint bumps of the item
+pub fn validate_batch_item(
+    collection: &Account<Collection>,
+    previous_ids: &[u64],
+    id: u64,
+    placeholder: &AccountInfo,
+    mint: &AccountInfo,
+) -> Result<(u8, u8)> {
+    require!(!previous_ids.contains(&id), ProtocolError::DuplicateInBatch);
+
+    let collection_key = collection.key();
+    let (placeholder_key, placeholder_bump) = Pubkey::find_program_address(
+        &[b"placeholder", collection_key.as_ref(), collection.placeholder_seed(id).as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(placeholder.key(), placeholder_key, ProtocolError::InvalidBatchAccount);
+
+    let (mint_key, mint_bump) = Pubkey::find_program_address(
+        &[b"mint", placeholder_key.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(mint.key(), mint_key, ProtocolError::InvalidBatchAccount);
+
+    require!(
+        placeholder.data_is_empty() && mint.data_is_empty(),
+        ProtocolError::PlaceholderAlreadyExists
+    );
+
+    Ok((placeholder_bump, mint_bump))
+}
@@ -7,23 +7,128 @@ pub use admin_remove::*;
 pub mod protocol_setting;
 pub use protocol_setting::*;
 
+pub mod migrate_protocol;
+pub use migrate_protocol::*;
+
+pub mod halt_and_snapshot;
+pub use halt_and_snapshot::*;
+
+pub mod treasury_setting;
+pub use treasury_setting::*;
+
 pub mod create_collection;
 pub use create_collection::*;
 
 pub mod close_collection;
 pub use close_collection::*;
 
+pub mod set_payout_destination;
+pub use set_payout_destination::*;
+
+pub mod set_collection_description;
+pub use set_collection_description::*;
+
+pub mod compact_collection;
+pub use compact_collection::*;
+
+pub mod migrate_collection;
+pub use migrate_collection::*;
+
+pub mod freeze_collection_metadata;
+pub use freeze_collection_metadata::*;
+
+pub mod set_pause_window;
+pub use set_pause_window::*;
+
+pub mod set_sale_start_time;
+pub use set_sale_start_time::*;
+pub mod set_sale_type;
+pub use set_sale_type::*;
+
+pub mod set_whitelist_only;
+pub use set_whitelist_only::*;
+pub mod set_whitelist_supply_cap;
+pub use set_whitelist_supply_cap::*;
+pub mod set_whitelist_end_time;
+pub use set_whitelist_end_time::*;
+pub mod set_enforce_unique_uri;
+pub use set_enforce_unique_uri::*;
+pub mod set_enable_holder_updates;
+pub use set_enable_holder_updates::*;
+pub mod set_airdrop_rent_payer;
+pub use set_airdrop_rent_payer::*;
+pub mod set_collection_metadata_authority;
+pub use set_collection_metadata_authority::*;
+pub mod set_require_memo_on_transfer;
+pub use set_require_memo_on_transfer::*;
+pub mod set_lazy_mint;
+pub use set_lazy_mint::*;
+pub mod set_signature_whitelist;
+pub use set_signature_whitelist::*;
+
+pub mod set_collection_creators;
+pub use set_collection_creators::*;
+
+pub mod set_owner_can_create;
+pub use set_owner_can_create::*;
+
+pub mod set_metaplex_collection;
+pub use set_metaplex_collection::*;
+
+pub mod set_gate_mint;
+pub use set_gate_mint::*;
+
+pub mod set_next_placeholder_id;
+pub use set_next_placeholder_id::*;
+
+pub mod verify_launch;
+pub use verify_launch::*;
+pub mod reconcile_supply;
+pub use reconcile_supply::*;
+
 pub mod transfer_nft;
 pub use transfer_nft::*;
 
 pub mod create_nft;
 pub use create_nft::*;
+pub mod holder_update_field;
+pub use holder_update_field::*;
 
 pub mod create_placeholder;
 pub use create_placeholder::*;
 
+pub mod mint_batch_for_collection;
+pub use mint_batch_for_collection::*;
+pub mod simulate_mint_batch;
+pub use simulate_mint_batch::*;
+
+pub mod bridge_metaplex_collection;
+pub use bridge_metaplex_collection::*;
+
 pub mod buy_placeholder;
 pub use buy_placeholder::*;
 
 pub mod airdrop_placeholder;
-pub use airdrop_placeholder::*;
\ No newline at end of file
+pub use airdrop_placeholder::*;
+
+pub mod direct_airdrop;
+pub use direct_airdrop::*;
+
+pub mod create_airdrop_claim;
+pub use create_airdrop_claim::*;
+
+pub mod claim_airdrop;
+pub use claim_airdrop::*;
+
+pub mod sweep_unclaimed;
+pub use sweep_unclaimed::*;
+
+pub mod get_placeholder_metadata;
+pub use get_placeholder_metadata::*;
+
+pub mod get_remaining_supply;
+pub use get_remaining_supply::*;
+pub mod verify_collection_integrity;
+pub use verify_collection_integrity::*;
+pub mod get_auth_pda_balance;
+pub use get_auth_pda_balance::*;
\ No newline at end of file
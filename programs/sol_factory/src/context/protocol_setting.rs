@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::{
     state::Protocol,
-    constant::multisig_wallet,
-    errors::SetupError,
+    constant::{multisig_wallet, admin_wallet},
+    errors::{SetupError, ProtocolError},
+    events::ProtocolLockChanged,
 };
 
 #[derive(Accounts)]
@@ -31,17 +32,38 @@ impl<'info> ProtocolSetting<'info> {
         of the team that is the highest security clearance for the enitre protocol.
 
         What these Instructions do:
-        - Initialize the Protocol account with the new settings.
+        - Initialize the Protocol account with the new settings, the treasury is only set on the first initialization 
+        (changing it afterwards goes through the timelocked TreasurySetting).
         - Toggle the lock on the Protocol: render the protocol useless/useful.
+        - Set the lock on the Protocol to an explicit value.
+        - Every lock change records locked_at and emits a ProtocolLockChanged event.
+        - Set the flat fee (in lamports) sent to the treasury on every mint, 0 disables it.
+        - Set the minimum public sale price (in lamports) of SOL priced collections, 0 disables it. 
+        Free mints go through the airdrop paths, a 0 price public sale would allow unlimited free mints.
+        - Rotate the key whose ED25519 signature authorizes airdrops (airdrop_signer), 
+        it defaults to the admin_wallet when (re)initialized without one.
+        - Enable/disable a single feature (constant::FEATURE_*) protocol wide, ex. during an incident without a redeploy. 
+        Every feature is enabled on the first initialization.
     */
 
     pub fn initialize_protocol(
         &mut self,
+        treasury: Pubkey,
     ) -> Result<()> {
 
         require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
         
         self.protocol.locked = true;
+        self.protocol.locked_at = Clock::get()?.unix_timestamp;
+
+        if self.protocol.treasury == Pubkey::default() {
+            self.protocol.treasury = treasury;
+            self.protocol.feature_flags = u64::MAX;
+        }
+
+        if self.protocol.airdrop_signer == Pubkey::default() {
+            self.protocol.airdrop_signer = admin_wallet::id();
+        }
 
         Ok(())
     }
@@ -52,7 +74,87 @@ impl<'info> ProtocolSetting<'info> {
 
         require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
         
-        self.protocol.locked = !self.protocol.locked;
+        self.update_lock(!self.protocol.locked)
+    }
+
+    pub fn set_locked_setting(
+        &mut self,
+        locked: bool,
+    ) -> Result<()> {
+
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+
+        self.update_lock(locked)
+    }
+
+    pub fn set_mint_fee(
+        &mut self,
+        mint_fee_lamports: u64,
+    ) -> Result<()> {
+
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+
+        self.protocol.mint_fee_lamports = mint_fee_lamports;
+
+        Ok(())
+    }
+
+    pub fn set_min_public_price(
+        &mut self,
+        min_public_price: u64,
+    ) -> Result<()> {
+
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+
+        self.protocol.min_public_price = min_public_price;
+
+        Ok(())
+    }
+
+    pub fn set_airdrop_signer(
+        &mut self,
+        airdrop_signer: Pubkey,
+    ) -> Result<()> {
+
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+
+        self.protocol.airdrop_signer = airdrop_signer;
+
+        Ok(())
+    }
+
+    pub fn set_feature_flag(
+        &mut self,
+        index: u8,
+        enabled: bool,
+    ) -> Result<()> {
+
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+        require!(index < 64, ProtocolError::InvalidFeatureIndex);
+
+        if enabled {
+            self.protocol.feature_flags |= 1 << index;
+        } else {
+            self.protocol.feature_flags &= !(1 << index);
+        }
+
+        Ok(())
+    }
+
+    fn update_lock(
+        &mut self,
+        locked: bool,
+    ) -> Result<()> {
+
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        self.protocol.locked = locked;
+        self.protocol.locked_at = timestamp;
+
+        emit!(ProtocolLockChanged {
+            locked,
+            timestamp,
+        });
 
         Ok(())
     }
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Collection, Protocol},
+    constant::multisig_wallet,
+    errors::{SetupError, ProtocolError},
+    events::SupplyReconciled,
+};
+
+#[derive(Accounts)]
+pub struct ReconcileSupply<'info> {
+    pub admin: Signer<'info>,
+    #[account(mut)]
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReconcileSupply<'info> {
+    pub fn reconcile(
+        &mut self,
+        total_supply_recomputed: u64,
+    ) -> Result<()> {
+
+        /*
+        
+            Reconcile Supply Ix:
+
+            Some security check:
+            - Check if the account that is interacting with this instruction is the mutlisig account 
+            of the team that is the highest security clearance for the enitre protocol.
+            - The protocol must be locked, nothing can mint while the count is being corrected.
+            - The recomputed supply can't be above the max_supply.
+
+            What these Instructions do:
+            - Recovery tool for accounting drift: overwrites collection.total_supply with a count 
+            recomputed off-chain (enumerating the minted placeholders), nothing else is touched.
+            - Emits a SupplyReconciled event with the old and new total_supply.
+        */
+
+        require!(self.protocol.locked, ProtocolError::ProtocolNotLocked);
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+        require!(
+            total_supply_recomputed <= self.collection.max_supply,
+            ProtocolError::InvalidMaxSupply
+        );
+
+        let old_total_supply = self.collection.total_supply;
+        self.collection.total_supply = total_supply_recomputed;
+
+        emit!(SupplyReconciled {
+            collection: self.collection.key(),
+            old_total_supply,
+            new_total_supply: total_supply_recomputed,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
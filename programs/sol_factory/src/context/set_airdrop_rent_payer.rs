@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetAirdropRentPayer<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetAirdropRentPayer<'info> {
+    pub fn set(
+        &mut self,
+        airdrop_rent_payer: Option<Pubkey>,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Airdrop Rent Payer Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+
+            What these Instructions do:
+            - Sets the wallet funding the buyer ATA rent of airdrop_placeholder and direct_airdrop (ex. a funded ops wallet), 
+            so large airdrops don't drain the admin, it must co-sign every airdrop. None goes back to the admin paying.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+
+        self.collection.airdrop_rent_payer = airdrop_rent_payer;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol};
+use crate::errors::ProtocolError;
+use crate::constant::MAX_COLLECTION_CREATORS;
+
+#[derive(Accounts)]
+pub struct SetCollectionCreators<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetCollectionCreators<'info> {
+    pub fn set(
+        &mut self,
+        creators: Vec<Pubkey>,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Collection Creators Ix:
+
+            Some security check:
+            - The collection PDA is derived from the signing owner.
+            - At most MAX_COLLECTION_CREATORS creators, the space is reserved on the collection.
+
+            What these Instructions do:
+            - Replaces the list of admins the owner delegates placeholder creation to, 
+            they can create placeholders for this collection only, an empty list removes them all.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(creators.len() <= MAX_COLLECTION_CREATORS, ProtocolError::TooManyCreators);
+
+        self.collection.creators = creators;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol};
+use crate::errors::ProtocolError;
+use crate::constant::MAX_DESCRIPTION_LEN;
+
+#[derive(Accounts)]
+#[instruction(description: String)]
+pub struct SetCollectionDescription<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+        realloc = collection.space() - collection.description.len() + description.len(),
+        realloc::payer = owner,
+        realloc::zero = false,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetCollectionDescription<'info> {
+    pub fn set(
+        &mut self,
+        description: String,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Collection Description Ix:
+
+            Some security check:
+            - The collection PDA is derived from the signing owner.
+            - The collection metadata must not be frozen.
+            - The description is at most MAX_DESCRIPTION_LEN bytes.
+
+            What these Instructions do:
+            - Replaces the on-chain description, the collection is realloc'd to the new length 
+            (the owner pays or gets refunded the rent difference).
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(!self.collection.frozen_metadata, ProtocolError::MetadataFrozen);
+        require!(description.len() <= MAX_DESCRIPTION_LEN, ProtocolError::DescriptionTooLong);
+
+        self.collection.description = description;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetCollectionMetadataAuthority<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetCollectionMetadataAuthority<'info> {
+    pub fn set(
+        &mut self,
+        collection_metadata_authority: bool,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Collection Metadata Authority Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+
+            What these Instructions do:
+            - Makes the collection meta_auth PDA [b"meta_auth", collection] the metadata update authority 
+            of the placeholders & AI NFTs created from now on instead of the global auth PDA, isolating 
+            the collection metadata from the other collections.
+            - Mints created before keep their update authority, the metadata updates (bridge_metaplex_collection, 
+            holder_update_field) sign with whichever PDA the mint records.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+
+        self.collection.collection_metadata_authority = collection_metadata_authority;
+
+        Ok(())
+    }
+}
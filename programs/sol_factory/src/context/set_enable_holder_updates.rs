@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetEnableHolderUpdates<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetEnableHolderUpdates<'info> {
+    pub fn set(
+        &mut self,
+        enable_holder_updates: bool,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Enable Holder Updates Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+
+            What these Instructions do:
+            - Lets the holders of the collection NFTs edit the HOLDER_EDITABLE_FIELDS of their own NFT 
+            metadata (holder_update_field), the other metadata fields stay admin only.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+
+        self.collection.enable_holder_updates = enable_holder_updates;
+
+        Ok(())
+    }
+}
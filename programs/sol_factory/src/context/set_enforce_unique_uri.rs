@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetEnforceUniqueUri<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetEnforceUniqueUri<'info> {
+    pub fn set(
+        &mut self,
+        enforce_unique_uri: bool,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Enforce Unique Uri Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+
+            What these Instructions do:
+            - Opt-in check that no two placeholders of the collection share a uri (ex. a token id left out of the uri), 
+            create_placeholder then needs the UriMarker PDA of the uri and mint_batch_for_collection is rejected.
+            - Only placeholders created while it is set get a marker, earlier uris aren't checked.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+
+        self.collection.enforce_unique_uri = enforce_unique_uri;
+
+        Ok(())
+    }
+}
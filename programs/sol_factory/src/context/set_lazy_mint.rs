@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetLazyMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetLazyMint<'info> {
+    pub fn set(
+        &mut self,
+        lazy_mint: bool,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Lazy Mint Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+
+            What these Instructions do:
+            - Lazy mint mode: create_placeholder only stores the placeholder and its mint parameters (LazyMint PDA), 
+            the mint is created by the first buy/airdrop/claim, so the mint rent is paid on demand instead of upfront.
+            - Only applies to the placeholders created from now on, already created mints are unchanged.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+
+        self.collection.lazy_mint = lazy_mint;
+
+        Ok(())
+    }
+}
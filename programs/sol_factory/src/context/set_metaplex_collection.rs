@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetMetaplexCollection<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetMetaplexCollection<'info> {
+    pub fn set(
+        &mut self,
+        metaplex_collection: Option<Pubkey>,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Metaplex Collection Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+            - The collection metadata must not be frozen.
+
+            What these Instructions do:
+            - Stores the Metaplex collection mint the collection is bridged to, placeholders can then be tagged 
+            with it through bridge_metaplex_collection. None opts the collection out.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+        require!(!self.collection.frozen_metadata, ProtocolError::MetadataFrozen);
+
+        self.collection.metaplex_collection = metaplex_collection;
+
+        Ok(())
+    }
+}
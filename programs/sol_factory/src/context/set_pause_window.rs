@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+use crate::constant::PAUSE_REASON_NONE;
+
+#[derive(Accounts)]
+pub struct SetPauseWindow<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPauseWindow<'info> {
+    pub fn set(
+        &mut self,
+        pause_start: i64,
+        pause_end: i64,
+        pause_reason: u8,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Pause Window Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+            - pause_start must be before pause_end.
+
+            What these Instructions do:
+            - Schedules a window [pause_start, pause_end) where buys and airdrops of the collection are rejected, 
+            so maintenance (ex. a metadata swap) can be planned ahead without flipping a flag live.
+            - pause_reason is a code frontends display during the pause (constant::PAUSE_REASON_*: 1 maintenance, 2 incident).
+            - Passing zeros for both removes the window and resets the pause_reason to 0 (none). An expired window 
+            keeps its stored reason, Collection::effective_pause_reason (returned by get_remaining_supply) reads 0 
+            outside the window.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+        require!(pause_start <= pause_end, ProtocolError::InvalidPauseWindow);
+
+        self.collection.pause_start = pause_start;
+        self.collection.pause_end = pause_end;
+        self.collection.pause_reason = if pause_end == 0 { PAUSE_REASON_NONE } else { pause_reason };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetPayoutDestination<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPayoutDestination<'info> {
+    pub fn set(
+        &mut self,
+        payout_destination: Pubkey,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Payout Destination Ix:
+
+            Some security check:
+            - The collection PDA is derived from the signing owner.
+            - The payout destination can only be set once.
+
+            What these Instructions do:
+            - Locks the collection proceeds to a pre-registered destination (ex. a multisig), 
+            so a compromised owner key can't redirect the sales.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.collection.payout_destination.is_none(), ProtocolError::PayoutDestinationAlreadySet);
+
+        self.collection.payout_destination = Some(payout_destination);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetRequireMemoOnTransfer<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetRequireMemoOnTransfer<'info> {
+    pub fn set(
+        &mut self,
+        require_memo_on_transfer: bool,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Require Memo On Transfer Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+
+            What these Instructions do:
+            - For compliance collections: the buyer ATA of every placeholder bought or claimed from now on 
+            gets the Token-2022 MemoTransfer extension enabled, transfers into it need a memo instruction 
+            (wallets must attach one). Airdrops (the buyer doesn't sign) and existing ATAs are not covered, 
+            and the holder can disable the requirement on its own ATA.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+
+        self.collection.require_memo_on_transfer = require_memo_on_transfer;
+
+        Ok(())
+    }
+}
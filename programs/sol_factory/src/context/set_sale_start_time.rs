@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Collection, Protocol},
+    errors::ProtocolError,
+    events::SaleStartTimeChanged,
+};
+
+#[derive(Accounts)]
+pub struct SetSaleStartTime<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetSaleStartTime<'info> {
+    pub fn set(
+        &mut self,
+        new_sale_start_time: i64,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Sale Start Time Ix:
+
+            Some security check:
+            - The collection PDA is derived from the signing owner.
+            - The new start time must be positive and before the sale_end_time.
+            - Once the sale has started the start time can only be pushed later, buyers can't be front-run 
+            by an earlier start.
+
+            What these Instructions do:
+            - Moves the launch date of the collection.
+            - Emits a SaleStartTimeChanged event with the old and new start time.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(new_sale_start_time > 0, ProtocolError::InvalidSaleTime);
+        require!(new_sale_start_time < self.collection.sale_end_time, ProtocolError::InvalidSaleTime);
+
+        let old_sale_start_time = self.collection.sale_start_time;
+        if Clock::get()?.unix_timestamp >= old_sale_start_time {
+            require!(new_sale_start_time > old_sale_start_time, ProtocolError::SaleAlreadyStarted);
+        }
+
+        self.collection.sale_start_time = new_sale_start_time;
+
+        emit!(SaleStartTimeChanged {
+            collection: self.collection.key(),
+            old_sale_start_time,
+            new_sale_start_time,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use crate::state::{Collection, Protocol, Admin, SaleType};
+use crate::errors::ProtocolError;
+use crate::constant::ADMIN_FEE;
+
+#[derive(Accounts)]
+pub struct SetSaleType<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetSaleType<'info> {
+    pub fn set(
+        &mut self,
+        sale_type: SaleType,
+        dutch_start_price: f32,
+        dutch_floor: f32,
+        dutch_decay_per_sec: f32,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Sale Type Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+            - The sale must not have started, buyers can't see the pricing change under them.
+            - Dutch prices must be finite, the decay positive or zero and dutch_floor <= dutch_start_price.
+            - SOL priced Dutch collections need a floor covering the ADMIN_FEE (it is taken out of the price) 
+            and the protocol min_public_price.
+
+            What these Instructions do:
+            - Switches the collection between a fixed price sale (placeholder price) and a Dutch auction 
+            priced dutch_start_price - dutch_decay_per_sec * (now - sale_start_time), never below dutch_floor.
+            - The Dutch prices are in the collection price unit (SOL or price_mint tokens), 
+            they are reset to 0 when switching back to Fixed.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+        require!(
+            Clock::get()?.unix_timestamp < self.collection.sale_start_time,
+            ProtocolError::SaleAlreadyStarted
+        );
+
+        match sale_type {
+            SaleType::Fixed => {
+                self.collection.dutch_start_price = 0.0;
+                self.collection.dutch_floor = 0.0;
+                self.collection.dutch_decay_per_sec = 0.0;
+            }
+            SaleType::Dutch => {
+                require!(
+                    dutch_start_price.is_finite() && dutch_floor.is_finite() && dutch_decay_per_sec.is_finite(),
+                    ProtocolError::InvalidDutchAuction
+                );
+                require!(dutch_floor >= 0.0 && dutch_decay_per_sec >= 0.0, ProtocolError::InvalidDutchAuction);
+                require!(dutch_floor <= dutch_start_price, ProtocolError::InvalidDutchAuction);
+                if self.collection.price_mint.is_none() {
+                    require!(
+                        (dutch_floor * LAMPORTS_PER_SOL as f32) as u64 >= ADMIN_FEE.max(self.protocol.min_public_price),
+                        ProtocolError::InvalidDutchAuction
+                    );
+                }
+
+                self.collection.dutch_start_price = dutch_start_price;
+                self.collection.dutch_floor = dutch_floor;
+                self.collection.dutch_decay_per_sec = dutch_decay_per_sec;
+            }
+        }
+
+        self.collection.sale_type = sale_type;
+
+        Ok(())
+    }
+}
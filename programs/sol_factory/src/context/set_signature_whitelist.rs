@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetSignatureWhitelist<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetSignatureWhitelist<'info> {
+    pub fn set(
+        &mut self,
+        signature_whitelist: bool,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Signature Whitelist Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+
+            What these Instructions do:
+            - Off-chain whitelist for the whitelist phase (whitelist_only or before whitelist_end_time): a wallet can buy with an ED25519 signature 
+            of (collection, buyer) by the protocol airdrop_signer instead of being airdropped, nothing is stored 
+            on-chain per whitelisted wallet until it buys (WhitelistReceipt, one buy per wallet).
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+
+        self.collection.signature_whitelist = signature_whitelist;
+
+        Ok(())
+    }
+}
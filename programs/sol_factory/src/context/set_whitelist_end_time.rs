@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetWhitelistEndTime<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetWhitelistEndTime<'info> {
+    pub fn set(
+        &mut self,
+        whitelist_end_time: i64,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Whitelist End Time Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+            - The end time must be 0 or within (sale_start_time, sale_end_time].
+
+            What these Instructions do:
+            - Opens a whitelist phase from the sale start until whitelist_end_time, the collection falls back 
+            to the public sale after it or as soon as the whitelist_supply_cap is reached, 0 removes the window.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+        require!(
+            whitelist_end_time == 0 
+                || (whitelist_end_time > self.collection.sale_start_time && whitelist_end_time <= self.collection.sale_end_time),
+            ProtocolError::InvalidSaleTime
+        );
+
+        self.collection.whitelist_end_time = whitelist_end_time;
+
+        Ok(())
+    }
+}
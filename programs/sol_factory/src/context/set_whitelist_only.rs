@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetWhitelistOnly<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetWhitelistOnly<'info> {
+    pub fn set(
+        &mut self,
+        whitelist_only: bool,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Whitelist Only Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+
+            What these Instructions do:
+            - Toggles the public sale of the collection, when whitelist_only is set buy_placeholder is rejected 
+            regardless of the sale time and only pre-authorized wallets (airdrops & claims) can mint. 
+            Unlike the whitelist_end_time window the collection never falls back to the public sale.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+
+        self.collection.whitelist_only = whitelist_only;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, Protocol, Admin};
+use crate::errors::ProtocolError;
+
+#[derive(Accounts)]
+pub struct SetWhitelistSupplyCap<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: this is ok because admin is setting up on owner behalf
+    pub owner: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"collection", owner.key().as_ref()],
+        bump = collection.bump,
+    )] 
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetWhitelistSupplyCap<'info> {
+    pub fn set(
+        &mut self,
+        whitelist_supply_cap: u64,
+    ) -> Result<()> {
+
+        /*
+        
+            Set Whitelist Supply Cap Ix:
+
+            Some security check:
+            - The admin_state.publickey must match the signing admin.
+            - The cap can't be above the max_supply.
+
+            What these Instructions do:
+            - Caps how many signature whitelist buys the whitelist phase allows (whitelist_minted), reaching it 
+            ends the whitelist window early and leaves the rest of the supply for the public sale, 0 removes the cap.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin_state.publickey == *self.admin.key, ProtocolError::UnauthorizedAdmin);
+        require!(whitelist_supply_cap <= self.collection.max_supply, ProtocolError::InvalidMaxSupply);
+
+        self.collection.whitelist_supply_cap = whitelist_supply_cap;
+
+        Ok(())
+    }
+}
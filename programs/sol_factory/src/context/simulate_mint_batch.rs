@@ -0,0 +1,73 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::program_error::ProgramError,
+};
+use crate::state::{Protocol, Collection, Admin};
+use super::{validate_batch, validate_batch_item};
+
+#[derive(Accounts)]
+pub struct SimulateMintBatch<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"admin_state", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_state: Account<'info, Admin>,
+    #[account(
+        seeds = [b"collection", collection.owner.key().as_ref()],
+        bump = collection.bump,
+    )]
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+}
+
+impl<'info> SimulateMintBatch<'info> {
+    pub fn simulate(
+        &self,
+        ids: Vec<u64>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<Vec<u64>> {
+
+        /*
+        
+            Simulate Mint Batch Ix:
+
+            Some security check:
+            - Runs the same batch level checks as mint_batch_for_collection (lock, batch mint feature, 
+            admin, batch size, unique uri & lazy mint collections, sold out), any of them fails the simulation.
+
+            What these Instructions do:
+            - Read only dry run of mint_batch_for_collection, same signer, ids and remaining_accounts 
+            ([placeholder_0, mint_0, placeholder_1, mint_1, ...]).
+            - Runs the per item checks of the batch (repeated id, PDAs, already created) on every item 
+            instead of stopping at the first failure.
+            - Returns one status per id as return data: 0 if the item would be created, 
+            otherwise the error code it would fail with.
+        */
+
+        validate_batch(
+            &self.protocol,
+            &self.collection,
+            &self.admin_state,
+            self.admin.key,
+            &ids,
+            remaining_accounts.len(),
+        )?;
+
+        Ok(ids
+            .iter()
+            .zip(remaining_accounts.chunks(2))
+            .enumerate()
+            .map(|(index, (&id, accounts))| {
+                match validate_batch_item(&self.collection, &ids[..index], id, &accounts[0], &accounts[1]) {
+                    Ok(_) => 0,
+                    Err(err) => u64::from(ProgramError::from(err)),
+                }
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{AirdropClaim, Collection, Protocol},
+    constant::{multisig_wallet, CLAIM_GRACE_PERIOD},
+    errors::{SetupError, ProtocolError},
+    events::{UnclaimedSwept, ClaimExpired},
+};
+
+#[derive(Accounts)]
+pub struct SweepUnclaimed<'info> {
+    pub admin: Signer<'info>,
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    #[account(
+        mut,
+        address = protocol.treasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SweepUnclaimed<'info> {
+    pub fn sweep(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+
+        /*
+        
+            Sweep Unclaimed Ix:
+
+            Some security check:
+            - Check if the account that is interacting with this instruction is the mutlisig account 
+            of the team that is the highest security clearance for the enitre protocol.
+            - Only after the collection sale_end_time + CLAIM_GRACE_PERIOD, 
+            or for claims past their own claim_deadline.
+            - Every remaining account must be an AirdropClaim of the collection.
+
+            What these Instructions do:
+            - Closes the never claimed AirdropClaim PDAs passed as remaining_accounts, their rent goes to the treasury.
+            - Emits a ClaimExpired event for every claim closed because of its deadline.
+            - Emits an UnclaimedSwept event with the number of claims closed and the lamports swept.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let grace_period_over = timestamp > self.collection.sale_end_time.saturating_add(CLAIM_GRACE_PERIOD);
+
+        let mut claims_closed: u32 = 0;
+        let mut lamports: u64 = 0;
+
+        for claim_info in remaining_accounts.iter() {
+            let claim = Account::<AirdropClaim>::try_from(claim_info)?;
+            require_keys_eq!(claim.collection, self.collection.key(), ProtocolError::InvalidBatchAccount);
+
+            if claim.is_expired(timestamp) {
+                emit!(ClaimExpired {
+                    collection: claim.collection,
+                    buyer: claim.buyer,
+                    claim_deadline: claim.claim_deadline,
+                });
+            } else {
+                require!(grace_period_over, ProtocolError::ClaimGracePeriodActive);
+            }
+
+            lamports += claim_info.lamports();
+            claims_closed += 1;
+
+            claim.close(self.treasury.to_account_info())?;
+        }
+
+        emit!(UnclaimedSwept {
+            collection: self.collection.key(),
+            claims_closed,
+            lamports,
+            timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -13,7 +13,7 @@ use {
             }},
         associated_token::{AssociatedToken, Create, create},  
         token::Token,
-        token_interface::{MintTo, mint_to, set_authority, SetAuthority}
+        token_interface::{MintTo, mint_to, set_authority, SetAuthority, thaw_account, ThawAccount}
     },
 };
 use spl_token_2022::instruction::burn;
@@ -55,7 +55,7 @@ pub struct TransferNft<'info> {
     #[account(
         mut,
         seeds = [b"collection", collection.owner.key().as_ref()],
-        bump,
+        bump = collection.bump,
     )] 
     pub collection: Account<'info, Collection>,
     #[account(
@@ -78,7 +78,7 @@ pub struct TransferNft<'info> {
     pub buyer_placeholder_mint_ata: AccountInfo<'info>,
     #[account(
         mut,
-        seeds = [b"placeholder", placeholder.collection.key().as_ref(), placeholder.id.to_le_bytes().as_ref()],
+        seeds = [b"placeholder", placeholder.collection.key().as_ref(), collection.placeholder_seed(placeholder.id).as_ref()],
         bump,
     )] 
     pub placeholder: Account<'info, Placeholder>,
@@ -120,7 +120,7 @@ impl<'info> TransferNft<'info> {
         */
 
         // Check if the protocol is locked, if it is, return an error
-        require!(!self.protocol.locked, ProtocolError::ProtocolLocked);
+        self.protocol.ensure_not_locked()?;
 
         let seeds: &[&[u8]; 2] = &[
             b"auth",
@@ -198,13 +198,30 @@ impl<'info> TransferNft<'info> {
 
         self.collection.mint_count += 1; 
 
-        {
+        let frozen = {
             let _before_burn_data = self.buyer_placeholder_mint_ata.data.borrow();
             let _before_burn_state = StateWithExtensions::<TokenAccount>::unpack(&_before_burn_data)?;
 
             require!(_before_burn_state.base.amount > 0, ProtocolError::InvalidBalancePreBurn);
 
             // msg!("before burn balance={}", _before_burn_state.base.amount);
+
+            _before_burn_state.base.is_frozen()
+        };
+
+        // Placeholders created frozen by default are thawed once the AI nft is delivered, a frozen account can't be burned
+        if frozen {
+            thaw_account(
+                CpiContext::new_with_signer(
+                    self.token_2022_program.to_account_info(),
+                    ThawAccount {
+                        account: self.buyer_placeholder_mint_ata.to_account_info(),
+                        mint: self.placeholder_mint.to_account_info(),
+                        authority: self.auth.to_account_info(),
+                    },
+                    signer_seeds
+                ),
+            )?;
         }
 
 
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Protocol,
+    constant::{multisig_wallet, TREASURY_TIMELOCK},
+    errors::{SetupError, ProtocolError},
+};
+
+#[derive(Accounts)]
+pub struct TreasurySetting<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TreasurySetting<'info> {
+
+    /*
+        
+        Change Treasury Ix:
+
+        Some security check:
+        - Check if the account that is interacting with this instruction is the mutlisig account 
+        of the team that is the highest security clearance for the enitre protocol.
+        - A proposed treasury can only be accepted once TREASURY_TIMELOCK has passed.
+
+        What these Instructions do:
+        - Propose a new treasury, every protocol fee keeps going to the current one until it's accepted.
+        - Accept the pending treasury after the timelock.
+    */
+
+    pub fn propose_treasury(
+        &mut self,
+        new_treasury: Pubkey,
+    ) -> Result<()> {
+
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+
+        self.protocol.pending_treasury = Some(new_treasury);
+        self.protocol.pending_treasury_at = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+
+    pub fn accept_treasury(
+        &mut self,
+    ) -> Result<()> {
+
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+
+        let pending_treasury = self.protocol.pending_treasury.ok_or(ProtocolError::NoPendingTreasury)?;
+        require!(
+            Clock::get()?.unix_timestamp >= self.protocol.pending_treasury_at + TREASURY_TIMELOCK,
+            ProtocolError::TreasuryTimelockActive
+        );
+
+        self.protocol.treasury = pending_treasury;
+        self.protocol.pending_treasury = None;
+        self.protocol.pending_treasury_at = 0;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::Collection;
+
+#[derive(Accounts)]
+pub struct VerifyCollectionIntegrity<'info> {
+    pub collection: Account<'info, Collection>,
+}
+
+impl<'info> VerifyCollectionIntegrity<'info> {
+    pub fn verify(
+        &self,
+    ) -> Result<u64> {
+
+        /*
+        
+            Verify Collection Integrity Ix:
+
+            What these Instructions do:
+            - Read only self audit for monitoring, cross checks the collection invariants 
+            (supply, sale time, pause window, whitelist cap, creators, description, Dutch prices, version) 
+            and returns them as a bitmask (constant::INTEGRITY_*, a set bit means the invariant holds) as return data.
+        */
+
+        Ok(self.collection.integrity_flags())
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Collection, Protocol},
+    constant::multisig_wallet,
+    errors::SetupError,
+    events::LaunchVerificationChanged,
+};
+
+#[derive(Accounts)]
+pub struct VerifyLaunch<'info> {
+    pub admin: Signer<'info>,
+    #[account(mut)]
+    pub collection: Account<'info, Collection>,
+    #[account(
+        seeds = [b"protocol"],
+        bump,
+    )]
+    pub protocol: Account<'info, Protocol>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> VerifyLaunch<'info> {
+    pub fn verify(
+        &mut self,
+        verified: bool,
+    ) -> Result<()> {
+
+        /*
+        
+            Verify Launch Ix:
+
+            Some security check:
+            - Check if the account that is interacting with this instruction is the mutlisig account 
+            of the team that is the highest security clearance for the enitre protocol.
+
+            What these Instructions do:
+            - Sets the launch_verified attestation on the collection (reviewed launch parameters), 
+            informational only, it doesn't gate minting.
+            - Emits a LaunchVerificationChanged event.
+        */
+
+        self.protocol.ensure_not_locked()?;
+        require!(self.admin.key() == multisig_wallet::id(), SetupError::Unauthorized);
+
+        self.collection.launch_verified = verified;
+
+        emit!(LaunchVerificationChanged {
+            collection: self.collection.key(),
+            verified,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -1,12 +1,14 @@
 use anchor_lang::error_code;
 
+// Each enum has its own code range so clients can categorize a failure from its code:
+// 6000+ setup, 6100+ buyer facing (the user can fix it), 6200+ protocol/admin (system issue)
 #[error_code]
 pub enum SetupError {
     #[msg("You are not authorized to perform this action")]
     Unauthorized,
 }
 
-#[error_code]
+#[error_code(offset = 6100)]
 pub enum BuyingError {
     #[msg("Listing is not Live yet, come back later!")]
     NotTimeYet,
@@ -20,8 +22,36 @@ pub enum BuyingError {
     WalletDoesNotMatch,
     #[msg("Token account doesn't match the expected mint")]
     TokenAccountMismatch,
+    #[msg("The collection is paused, come back later!")]
+    CollectionPaused,
+    #[msg("The collection is priced in an SPL token, payment accounts are missing")]
+    PaymentAccountsMissing,
+    #[msg("The collection has no public sale, only whitelisted wallets can mint")]
+    WhitelistOnly,
+    #[msg("Not enough SOL to pay the mint price and fees")]
+    InsufficientFunds,
+    #[msg("You don't hold the token required to mint this collection")]
+    GateNotSatisfied,
+    #[msg("The placeholder is not the next one to mint")]
+    NotNextPlaceholder,
+    #[msg("You don't hold this NFT")]
+    NotHolder,
+    #[msg("The whitelist allocation is sold out!")]
+    WhitelistSoldOut,
+    #[msg("The price changed, check the new price and try again")]
+    PriceChanged,
+    #[msg("The wallet isn't whitelisted for this collection")]
+    NotWhitelisted,
+    #[msg("The next placeholder is already sold, the cursor must be moved with set_next_placeholder_id")]
+    NextPlaceholderSold,
+    #[msg("The whitelist buy needs the instructions sysvar and the ED25519 signature instruction")]
+    WhitelistSignatureMissing,
+    #[msg("The whitelist signature is malformed or not signed by the airdrop signer")]
+    InvalidWhitelistSignature,
+    #[msg("The whitelist signature expired, request a new one")]
+    WhitelistSignatureExpired,
 }
-#[error_code]
+#[error_code(offset = 6200)]
 pub enum ProtocolError {
     #[msg("The Protocol is locked, you can't perform this action")]
     ProtocolLocked,
@@ -29,8 +59,16 @@ pub enum ProtocolError {
     UnauthorizedAdmin,
     #[msg("Airdrop instructions not correct")]
     InstructionsNotCorrect,
+    #[msg("Unsupported airdrop signature version")]
+    UnsupportedSignatureVersion,
+    #[msg("The airdrop signature has expired")]
+    SignatureExpired,
+    #[msg("The admin can't airdrop to itself")]
+    SelfAirdrop,
     #[msg("Invalid Sale Time")]
     InvalidSaleTime,
+    #[msg("Invalid Pause Window")]
+    InvalidPauseWindow,
     #[msg("Invalid Max Supply")]
     InvalidMaxSupply,
     #[msg("Invalid Price")]
@@ -47,4 +85,84 @@ pub enum ProtocolError {
     InvalidBalancePreBurn,
     #[msg("Invalid balance post burn")]
     InvalidBalancePostBurn,
+    #[msg("Payout destination is already set")]
+    PayoutDestinationAlreadySet,
+    #[msg("Proceeds can only be sent to the payout destination")]
+    PayoutDestinationMismatch,
+    #[msg("The collection metadata is frozen")]
+    MetadataFrozen,
+    #[msg("There is no pending treasury to accept")]
+    NoPendingTreasury,
+    #[msg("The treasury timelock has not passed yet")]
+    TreasuryTimelockActive,
+    #[msg("Invalid batch size")]
+    InvalidBatchSize,
+    #[msg("Batch account does not match the expected PDA")]
+    InvalidBatchAccount,
+    #[msg("Too many creators for the collection")]
+    TooManyCreators,
+    #[msg("The mint authority does not match the auth PDA")]
+    MintAuthorityMismatch,
+    #[msg("The metadata update authority does not match the auth PDA")]
+    MetadataAuthorityMismatch,
+    #[msg("The collection is not bridged to a Metaplex collection")]
+    MetaplexCollectionNotSet,
+    #[msg("The claim grace period has not passed yet")]
+    ClaimGracePeriodActive,
+    #[msg("The placeholder already exists with different parameters")]
+    PlaceholderAlreadyExists,
+    #[msg("The collection is already on the latest version")]
+    CollectionAlreadyMigrated,
+    #[msg("Too many extra attributes")]
+    TooManyAttributes,
+    #[msg("The collection account is not the collection PDA of its owner")]
+    InvalidCollectionAccount,
+    #[msg("The sale has already started, the start time can only be pushed later")]
+    SaleAlreadyStarted,
+    #[msg("The description is too long")]
+    DescriptionTooLong,
+    #[msg("The same id is listed twice in the batch")]
+    DuplicateInBatch,
+    #[msg("The claim window is closed")]
+    ClaimWindowClosed,
+    #[msg("This feature is disabled on the protocol")]
+    FeatureDisabled,
+    #[msg("Invalid feature index")]
+    InvalidFeatureIndex,
+    #[msg("The mint metadata pointer doesn't point to the mint")]
+    MetadataPointerMismatch,
+    #[msg("The token account owner is not the buyer")]
+    AtaOwnerMismatch,
+    #[msg("Invalid Dutch auction prices")]
+    InvalidDutchAuction,
+    #[msg("The Protocol must be locked to perform this action")]
+    ProtocolNotLocked,
+    #[msg("A placeholder of this collection already uses this uri")]
+    DuplicateUri,
+    #[msg("The collection enforces unique uris, the uri marker account is missing")]
+    UriMarkerMissing,
+    #[msg("The symbol is too long")]
+    SymbolTooLong,
+    #[msg("Holder updates are disabled for this collection")]
+    HolderUpdatesDisabled,
+    #[msg("The field can't be edited by the holder or the value is too long")]
+    FieldNotHolderEditable,
+    #[msg("The username is too long")]
+    UsernameTooLong,
+    #[msg("The collection airdrop rent payer must sign")]
+    RentPayerMismatch,
+    #[msg("The lazy mint account of the placeholder is missing")]
+    LazyMintMissing,
+    #[msg("Lazy mint collections must create placeholders one by one")]
+    LazyMintUnsupported,
+    #[msg("The ED25519 signature instruction must be placed before this instruction")]
+    MissingSignatureInstruction,
+    #[msg("The instructions sysvar account is missing or not the instructions sysvar")]
+    InstructionsSysvarMissing,
+    #[msg("The protocol account is already on the current layout")]
+    ProtocolAlreadyMigrated,
+    #[msg("The placeholder doesn't belong to the passed collection")]
+    CollectionMismatch,
+    #[msg("Collections enforcing unique uris must create placeholders one by one")]
+    UniqueUriBatchUnsupported,
 }
\ No newline at end of file
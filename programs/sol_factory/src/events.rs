@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::SalePhase;
+
+#[event]
+pub struct ProtocolLockChanged {
+    pub locked: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnclaimedSwept {
+    pub collection: Pubkey,
+    pub claims_closed: u32,
+    pub lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PhaseEntered {
+    pub collection: Pubkey,
+    pub phase: SalePhase,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LaunchVerificationChanged {
+    pub collection: Pubkey,
+    pub verified: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SaleStartTimeChanged {
+    pub collection: Pubkey,
+    pub old_sale_start_time: i64,
+    pub new_sale_start_time: i64,
+}
+
+#[event]
+pub struct ClaimExpired {
+    pub collection: Pubkey,
+    pub buyer: Pubkey,
+    pub claim_deadline: i64,
+}
+
+#[event]
+pub struct SupplyReconciled {
+    pub collection: Pubkey,
+    pub old_total_supply: u64,
+    pub new_total_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionCreated {
+    pub collection: Pubkey,
+    pub owner: Pubkey,
+    pub reference: Pubkey,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct PlaceholderCreated {
+    pub collection: Pubkey,
+    pub placeholder: Pubkey,
+    pub id: u64,
+    pub created_by: Pubkey,
+}
+
+#[event]
+pub struct AirdropExecuted {
+    pub collection: Pubkey,
+    pub buyer: Pubkey,
+    pub mint: Pubkey,
+    pub campaign_id: u32,
+}
+
+#[event]
+pub struct ProtocolHalted {
+    pub timestamp: i64,
+    pub reason_code: u8,
+    pub total_collections: u64,
+}
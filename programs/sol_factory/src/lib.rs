@@ -2,8 +2,11 @@ use anchor_lang::prelude::*;
 mod state;
 mod errors;
 mod constant;
+mod events;
 mod context;
 use context::*;
+use state::{PlaceholderMetadata, PlaceholderAddresses, CollectionSupply, SweepableBalances, SaleType};
+pub use constant::CREATE_PLACEHOLDER_CU_ESTIMATE;
 
 declare_id!("E72hAXTsSJn79Xb9mBB7kmK9VoX3HGNaoCyrqEqCE6dd");
 
@@ -11,14 +14,69 @@ declare_id!("E72hAXTsSJn79Xb9mBB7kmK9VoX3HGNaoCyrqEqCE6dd");
 pub mod sol_factory {
     use super::*;
 
-    pub fn initialize_protocol_account(ctx: Context<ProtocolSetting>) -> Result<()> {
-        ctx.accounts.initialize_protocol()
+    pub fn initialize_protocol_account(ctx: Context<ProtocolSetting>, 
+        treasury: Pubkey
+    ) -> Result<()> {
+        ctx.accounts.initialize_protocol(treasury)
+    }
+
+    pub fn migrate_protocol(ctx: Context<MigrateProtocol>, 
+        treasury: Pubkey
+    ) -> Result<()> {
+        ctx.accounts.migrate(treasury)
     }
 
     pub fn lock_protocol(ctx: Context<ProtocolSetting>) -> Result<()> {
         ctx.accounts.change_locked_setting()
     }
 
+    pub fn set_protocol_lock(ctx: Context<ProtocolSetting>, 
+        locked: bool
+    ) -> Result<()> {
+        ctx.accounts.set_locked_setting(locked)
+    }
+
+    pub fn halt_and_snapshot(ctx: Context<HaltAndSnapshot>, 
+        reason_code: u8
+    ) -> Result<()> {
+        ctx.accounts.halt(reason_code)
+    }
+
+    pub fn set_mint_fee(ctx: Context<ProtocolSetting>, 
+        mint_fee_lamports: u64
+    ) -> Result<()> {
+        ctx.accounts.set_mint_fee(mint_fee_lamports)
+    }
+
+    pub fn set_min_public_price(ctx: Context<ProtocolSetting>, 
+        min_public_price: u64
+    ) -> Result<()> {
+        ctx.accounts.set_min_public_price(min_public_price)
+    }
+
+    pub fn set_airdrop_signer(ctx: Context<ProtocolSetting>, 
+        airdrop_signer: Pubkey
+    ) -> Result<()> {
+        ctx.accounts.set_airdrop_signer(airdrop_signer)
+    }
+
+    pub fn set_feature_flag(ctx: Context<ProtocolSetting>, 
+        index: u8,
+        enabled: bool,
+    ) -> Result<()> {
+        ctx.accounts.set_feature_flag(index, enabled)
+    }
+
+    pub fn set_treasury(ctx: Context<TreasurySetting>, 
+        new_treasury: Pubkey
+    ) -> Result<()> {
+        ctx.accounts.propose_treasury(new_treasury)
+    }
+
+    pub fn accept_treasury(ctx: Context<TreasurySetting>) -> Result<()> {
+        ctx.accounts.accept_treasury()
+    }
+
     pub fn initialize_admin_account(ctx: Context<AdminInit>, 
         username: String
     ) -> Result<()> {
@@ -39,14 +97,166 @@ pub mod sol_factory {
         max_supply: u64, 
         price: f32, 
         stable_id: String, 
+        salt: Option<[u8; 8]>,
+        interest_rate: Option<i16>,
+        description: String,
     ) -> Result<()> {
-        ctx.accounts.create(reference, name, symbol, url, sale_start_time, sale_end_time, max_supply, price, stable_id, ctx.bumps)
+        ctx.accounts.create(reference, name, symbol, url, sale_start_time, sale_end_time, max_supply, price, stable_id, salt, interest_rate, description, ctx.bumps)
     }
 
     pub fn close_collection(ctx: Context<CloseCollection>) -> Result<()> {
         ctx.accounts.close()
     }
 
+    pub fn set_payout_destination(ctx: Context<SetPayoutDestination>, 
+        payout_destination: Pubkey
+    ) -> Result<()> {
+        ctx.accounts.set(payout_destination)
+    }
+
+    pub fn set_collection_description(ctx: Context<SetCollectionDescription>, 
+        description: String
+    ) -> Result<()> {
+        ctx.accounts.set(description)
+    }
+
+    pub fn compact_collection(ctx: Context<CompactCollection>) -> Result<()> {
+        ctx.accounts.compact()
+    }
+
+    pub fn migrate_collection(ctx: Context<MigrateCollection>) -> Result<()> {
+        ctx.accounts.migrate(ctx.bumps)
+    }
+
+    pub fn freeze_collection_metadata(ctx: Context<FreezeCollectionMetadata>) -> Result<()> {
+        ctx.accounts.freeze()
+    }
+
+    pub fn set_pause_window(ctx: Context<SetPauseWindow>, 
+        pause_start: i64, 
+        pause_end: i64,
+        pause_reason: u8,
+    ) -> Result<()> {
+        ctx.accounts.set(pause_start, pause_end, pause_reason)
+    }
+
+    pub fn set_sale_start_time(ctx: Context<SetSaleStartTime>, 
+        new_sale_start_time: i64
+    ) -> Result<()> {
+        ctx.accounts.set(new_sale_start_time)
+    }
+
+    pub fn set_sale_type(ctx: Context<SetSaleType>, 
+        sale_type: SaleType,
+        dutch_start_price: f32,
+        dutch_floor: f32,
+        dutch_decay_per_sec: f32,
+    ) -> Result<()> {
+        ctx.accounts.set(sale_type, dutch_start_price, dutch_floor, dutch_decay_per_sec)
+    }
+
+    pub fn set_whitelist_only(ctx: Context<SetWhitelistOnly>, 
+        whitelist_only: bool
+    ) -> Result<()> {
+        ctx.accounts.set(whitelist_only)
+    }
+
+    pub fn set_whitelist_supply_cap(ctx: Context<SetWhitelistSupplyCap>, 
+        whitelist_supply_cap: u64
+    ) -> Result<()> {
+        ctx.accounts.set(whitelist_supply_cap)
+    }
+
+    pub fn set_whitelist_end_time(ctx: Context<SetWhitelistEndTime>, 
+        whitelist_end_time: i64
+    ) -> Result<()> {
+        ctx.accounts.set(whitelist_end_time)
+    }
+
+    pub fn set_enforce_unique_uri(ctx: Context<SetEnforceUniqueUri>, 
+        enforce_unique_uri: bool
+    ) -> Result<()> {
+        ctx.accounts.set(enforce_unique_uri)
+    }
+
+    pub fn set_enable_holder_updates(ctx: Context<SetEnableHolderUpdates>, 
+        enable_holder_updates: bool
+    ) -> Result<()> {
+        ctx.accounts.set(enable_holder_updates)
+    }
+
+    pub fn set_airdrop_rent_payer(ctx: Context<SetAirdropRentPayer>, 
+        airdrop_rent_payer: Option<Pubkey>
+    ) -> Result<()> {
+        ctx.accounts.set(airdrop_rent_payer)
+    }
+
+    pub fn set_collection_metadata_authority(ctx: Context<SetCollectionMetadataAuthority>, 
+        collection_metadata_authority: bool
+    ) -> Result<()> {
+        ctx.accounts.set(collection_metadata_authority)
+    }
+
+    pub fn set_require_memo_on_transfer(ctx: Context<SetRequireMemoOnTransfer>, 
+        require_memo_on_transfer: bool
+    ) -> Result<()> {
+        ctx.accounts.set(require_memo_on_transfer)
+    }
+
+    pub fn set_lazy_mint(ctx: Context<SetLazyMint>, 
+        lazy_mint: bool
+    ) -> Result<()> {
+        ctx.accounts.set(lazy_mint)
+    }
+
+    pub fn set_signature_whitelist(ctx: Context<SetSignatureWhitelist>, 
+        signature_whitelist: bool
+    ) -> Result<()> {
+        ctx.accounts.set(signature_whitelist)
+    }
+
+    pub fn set_collection_creators(ctx: Context<SetCollectionCreators>, 
+        creators: Vec<Pubkey>
+    ) -> Result<()> {
+        ctx.accounts.set(creators)
+    }
+
+    pub fn set_owner_can_create(ctx: Context<SetOwnerCanCreate>, 
+        owner_can_create: bool
+    ) -> Result<()> {
+        ctx.accounts.set(owner_can_create)
+    }
+
+    pub fn set_metaplex_collection(ctx: Context<SetMetaplexCollection>, 
+        metaplex_collection: Option<Pubkey>
+    ) -> Result<()> {
+        ctx.accounts.set(metaplex_collection)
+    }
+
+    pub fn set_gate_mint(ctx: Context<SetGateMint>, 
+        gate_mint: Option<Pubkey>
+    ) -> Result<()> {
+        ctx.accounts.set(gate_mint)
+    }
+
+    pub fn set_next_placeholder_id(ctx: Context<SetNextPlaceholderId>, 
+        next_placeholder_id: u64
+    ) -> Result<()> {
+        ctx.accounts.set(next_placeholder_id)
+    }
+
+    pub fn verify_launch(ctx: Context<VerifyLaunch>, 
+        verified: bool
+    ) -> Result<()> {
+        ctx.accounts.verify(verified)
+    }
+
+    pub fn reconcile_supply(ctx: Context<ReconcileSupply>, 
+        total_supply_recomputed: u64
+    ) -> Result<()> {
+        ctx.accounts.reconcile(total_supply_recomputed)
+    }
+
     pub fn create_nft(ctx: Context<CreateNft>, 
         id: u64, 
         uri: String, 
@@ -56,23 +266,96 @@ pub mod sol_factory {
         ctx.accounts.create(id, uri, name, attributes, ctx.bumps)
     }
 
+    pub fn holder_update_field(ctx: Context<HolderUpdateField>, 
+        field: String,
+        value: String,
+    ) -> Result<()> {
+        ctx.accounts.update(field, value, ctx.bumps)
+    }
+
     pub fn transfer_nft(ctx: Context<TransferNft>) -> Result<()> {
         ctx.accounts.transfer(ctx.bumps)
     }
 
     pub fn create_placeholder(ctx: Context<CreatePlaceholder>, 
         id: u64, 
-        uri: String
+        uri: String,
+        default_frozen: bool,
+        extra_attributes: Vec<Attributes>,
+        symbol_override: Option<String>,
+    ) -> Result<PlaceholderAddresses> {
+        ctx.accounts.create(id, uri, default_frozen, extra_attributes, symbol_override, ctx.bumps)
+    }
+
+    pub fn mint_batch_for_collection<'info>(ctx: Context<'_, '_, '_, 'info, MintBatchForCollection<'info>>, 
+        ids: Vec<u64>, 
+        uri: String,
+        default_frozen: bool,
+    ) -> Result<()> {
+        ctx.accounts.create_batch(ids, uri, default_frozen, ctx.bumps, ctx.remaining_accounts)
+    }
+
+    pub fn simulate_mint_batch<'info>(ctx: Context<'_, '_, '_, 'info, SimulateMintBatch<'info>>, 
+        ids: Vec<u64>,
+    ) -> Result<Vec<u64>> {
+        ctx.accounts.simulate(ids, ctx.remaining_accounts)
+    }
+
+    pub fn bridge_metaplex_collection(ctx: Context<BridgeMetaplexCollection>) -> Result<()> {
+        ctx.accounts.bridge(ctx.bumps)
+    }
+
+    pub fn buy_placeholder(ctx: Context<BuyPlaceholder>, 
+        expected_price: u64
+    ) -> Result<()> {
+        ctx.accounts.buy(expected_price, ctx.bumps)
+    }
+
+    pub fn buy_next(ctx: Context<BuyPlaceholder>, 
+        expected_price: u64
     ) -> Result<()> {
-        ctx.accounts.create(id, uri, ctx.bumps)
+        ctx.accounts.buy_next(expected_price, ctx.bumps)
     }
 
-    pub fn buy_placeholder(ctx: Context<BuyPlaceholder>) -> Result<()> {
-        ctx.accounts.buy(ctx.bumps)
+    pub fn airdrop_placeholder(ctx: Context<AirdropPlaceholder>, 
+        force: bool,
+        campaign_id: u32
+    ) -> Result<()> {
+        ctx.accounts.airdrop(force, campaign_id, ctx.bumps)
     }
 
-    pub fn airdrop_placeholder(ctx: Context<AirdropPlaceholder>) -> Result<()> {
+    pub fn direct_airdrop(ctx: Context<DirectAirdrop>) -> Result<()> {
         ctx.accounts.airdrop(ctx.bumps)
     }
+
+    pub fn create_airdrop_claim(ctx: Context<CreateAirdropClaim>, 
+        claim_deadline: i64
+    ) -> Result<()> {
+        ctx.accounts.create(claim_deadline)
+    }
+
+    pub fn claim_airdrop(ctx: Context<ClaimAirdrop>) -> Result<()> {
+        ctx.accounts.claim(ctx.bumps)
+    }
+
+    pub fn sweep_unclaimed<'info>(ctx: Context<'_, '_, 'info, 'info, SweepUnclaimed<'info>>) -> Result<()> {
+        ctx.accounts.sweep(ctx.remaining_accounts)
+    }
+
+    pub fn get_placeholder_metadata(ctx: Context<GetPlaceholderMetadata>) -> Result<PlaceholderMetadata> {
+        ctx.accounts.get()
+    }
+
+    pub fn get_remaining_supply(ctx: Context<GetRemainingSupply>) -> Result<CollectionSupply> {
+        ctx.accounts.get()
+    }
+
+    pub fn verify_collection_integrity(ctx: Context<VerifyCollectionIntegrity>) -> Result<u64> {
+        ctx.accounts.verify()
+    }
+
+    pub fn get_auth_pda_balance(ctx: Context<GetAuthPdaBalance>) -> Result<SweepableBalances> {
+        ctx.accounts.get()
+    }
 }
 
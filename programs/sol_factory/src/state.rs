@@ -1,13 +1,41 @@
 use anchor_lang::prelude::*;
+use crate::constant::{
+    MAX_COLLECTION_CREATORS, MAX_DESCRIPTION_LEN, COLLECTION_VERSION, REGISTRY_PAGE_SIZE, PAUSE_REASON_NONE,
+    INTEGRITY_SUPPLY, INTEGRITY_SALE_TIME, INTEGRITY_PAUSE_WINDOW, INTEGRITY_WHITELIST_CAP, 
+    INTEGRITY_CREATORS, INTEGRITY_DESCRIPTION, INTEGRITY_DUTCH_PRICES, INTEGRITY_VERSION,
+};
+use crate::errors::ProtocolError;
 
 // Setup State
 #[account]
 pub struct Protocol {
     pub locked: bool,
+    pub treasury: Pubkey,
+    pub pending_treasury: Option<Pubkey>,
+    pub pending_treasury_at: i64,
+    pub locked_at: i64,
+    pub mint_fee_lamports: u64,
+    pub feature_flags: u64,
+    pub min_public_price: u64,
+    pub airdrop_signer: Pubkey,
 }
 
 impl Space for Protocol {
-    const INIT_SPACE: usize = 8 + 1;
+    const INIT_SPACE: usize = 8 + 1 + 32 + (1 + 32) + 8 + 8 + 8 + 8 + 8 + 32;
+}
+
+impl Protocol {
+    // One bit per feature (constant::FEATURE_*), a set bit means enabled
+    pub fn is_feature_enabled(&self, feature: u8) -> bool {
+        self.feature_flags & (1 << feature) != 0
+    }
+
+    // Global freeze guard, the first check of every state mutating instruction 
+    // (except the multisig protocol/treasury settings and reconcile_supply which needs the lock)
+    pub fn ensure_not_locked(&self) -> Result<()> {
+        require!(!self.locked, ProtocolError::ProtocolLocked);
+        Ok(())
+    }
 }
 
 #[account]
@@ -35,27 +63,305 @@ pub struct Collection {
     pub mint_count: u64,
     pub price: f32,
     pub stable_id: String,
+    pub salt: [u8; 8],
+    pub payout_destination: Option<Pubkey>,
+    pub frozen_metadata: bool,
+    pub interest_rate: Option<i16>,
+    pub pause_start: i64,
+    pub pause_end: i64,
+    pub price_mint: Option<Pubkey>,
+    pub whitelist_only: bool,
+    pub creators: Vec<Pubkey>,
+    pub metaplex_collection: Option<Pubkey>,
+    pub last_observed_phase: SalePhase,
+    pub gate_mint: Option<Pubkey>,
+    pub launch_verified: bool,
+    pub version: u8,
+    pub owner_can_create: bool,
+    pub description: String,
+    pub next_placeholder_id: u64,
+    pub bump: u8,
+    pub sale_type: SaleType,
+    pub dutch_start_price: f32,
+    pub dutch_floor: f32,
+    pub dutch_decay_per_sec: f32,
+    pub enforce_unique_uri: bool,
+    pub created_at: i64,
+    pub enable_holder_updates: bool,
+    pub whitelist_supply_cap: u64,
+    pub whitelist_minted: u64,
+    pub pause_reason: u8,
+    pub airdrop_rent_payer: Option<Pubkey>,
+    pub collection_metadata_authority: bool,
+    pub require_memo_on_transfer: bool,
+    pub lazy_mint: bool,
+    pub signature_whitelist: bool,
+    pub whitelist_end_time: i64,
 }
 
 impl Space for Collection {
-    const INIT_SPACE: usize = 8 + 32 + 4 + 4 + 32 + 4 + 8 + 8 + 8 + 8 + 8 + 4 + 4 + 8 + 8; 
+    const INIT_SPACE: usize = 8 + 32 + 4 + 4 + 32 + 4 + 8 + 8 + 8 + 8 + 8 + 4 + 4 + 8 + 8 + 8 + (1 + 32) + 1 + (1 + 2) + 8 + 8 + (1 + 32) + 1 + (4 + 32 * MAX_COLLECTION_CREATORS) + (1 + 32) + 1 + (1 + 32) + 1 + 1 + 1 + 4 + 8 + 1 + 1 + 4 + 4 + 4 + 1 + 8 + 1 + 8 + 8 + 1 + (1 + 32) + 1 + 1 + 1 + 1 + 8; 
+}
+
+impl Collection {
+    // Single source of the supply invariant (total_supply <= max_supply) used by every create/mint path
+    pub fn is_sold_out(&self) -> bool {
+        self.total_supply >= self.max_supply
+    }
+
+    // Scheduled maintenance window [pause_start, pause_end), zeros disable it
+    pub fn is_paused(&self, now: i64) -> bool {
+        self.pause_end != 0 && self.pause_start <= now && now < self.pause_end
+    }
+
+    // The stored pause_reason only applies while the window is active, outside of it the reason is none
+    pub fn effective_pause_reason(&self, now: i64) -> u8 {
+        if self.is_paused(now) {
+            self.pause_reason
+        } else {
+            PAUSE_REASON_NONE
+        }
+    }
+
+    // The whitelist allocation is used up, 0 = no separate cap
+    pub fn is_whitelist_sold_out(&self) -> bool {
+        self.whitelist_supply_cap != 0 && self.whitelist_minted >= self.whitelist_supply_cap
+    }
+
+    // Time derived sale phase, whitelist only collections never enter the public phase, 
+    // the others fall back to it after whitelist_end_time or once the whitelist cap is reached
+    pub fn phase(&self, now: i64) -> SalePhase {
+        if now < self.sale_start_time {
+            SalePhase::NotStarted
+        } else if now > self.sale_end_time {
+            SalePhase::Ended
+        } else if self.whitelist_only || (now < self.whitelist_end_time && !self.is_whitelist_sold_out()) {
+            SalePhase::Whitelist
+        } else {
+            SalePhase::Public
+        }
+    }
+
+    // Same conditions buy_placeholder checks before taking a payment
+    pub fn is_public_sale_active(&self, now: i64) -> bool {
+        self.phase(now) == SalePhase::Public
+            && !self.is_sold_out()
+            && !self.is_paused(now)
+            && now >= self.sale_start_time
+            && now <= self.sale_end_time
+    }
+
+    // Fixed collections sell at the placeholder price, Dutch ones decay linearly 
+    // from dutch_start_price at the sale start down to dutch_floor
+    pub fn current_price(&self, now: i64, fixed_price: f32) -> f32 {
+        match self.sale_type {
+            SaleType::Fixed => fixed_price,
+            SaleType::Dutch => {
+                let elapsed = now.saturating_sub(self.sale_start_time).max(0) as f32;
+                (self.dutch_start_price - self.dutch_decay_per_sec * elapsed).max(self.dutch_floor)
+            }
+        }
+    }
+
+    // One bit per invariant (constant::INTEGRITY_*) the create/update instructions enforce, a set bit means it holds
+    pub fn integrity_flags(&self) -> u64 {
+        [
+            (INTEGRITY_SUPPLY, self.total_supply <= self.max_supply),
+            (INTEGRITY_SALE_TIME, 0 < self.sale_start_time && self.sale_start_time < self.sale_end_time),
+            (INTEGRITY_PAUSE_WINDOW, self.pause_start <= self.pause_end && (self.pause_end != 0 || self.pause_reason == PAUSE_REASON_NONE)),
+            (
+                INTEGRITY_WHITELIST_CAP, 
+                self.whitelist_supply_cap <= self.max_supply 
+                    && (self.whitelist_supply_cap == 0 || self.whitelist_minted <= self.whitelist_supply_cap),
+            ),
+            (INTEGRITY_CREATORS, self.creators.len() <= MAX_COLLECTION_CREATORS),
+            (INTEGRITY_DESCRIPTION, self.description.len() <= MAX_DESCRIPTION_LEN),
+            (INTEGRITY_DUTCH_PRICES, self.sale_type == SaleType::Fixed || self.dutch_floor <= self.dutch_start_price),
+            (INTEGRITY_VERSION, self.version == COLLECTION_VERSION),
+        ]
+        .into_iter()
+        .filter(|(_, holds)| *holds)
+        .fold(0, |flags, (bit, _)| flags | 1 << bit)
+    }
+
+    // Exact space needed for the current string lengths, never below the fixed INIT_SPACE
+    pub fn space(&self) -> usize {
+        Collection::INIT_SPACE + self.name.len() + self.symbol.len() + self.url.len() + self.stable_id.len() + self.description.len()
+    }
+
+    // The placeholder PDA is derived from the id xor'd with the collection salt, 
+    // a zero salt keeps the original [b"placeholder", collection, id] derivation
+    pub fn placeholder_seed(&self, id: u64) -> [u8; 8] {
+        (id ^ u64::from_le_bytes(self.salt)).to_le_bytes()
+    }
 }
 
 #[account]
 pub struct Placeholder {
     pub id: u64,
     pub collection: Pubkey,
-    pub reference: String,
+    pub reference: Pubkey,
     pub name: String,
     pub price: f32,
     pub time_stamp: i64,
+    pub created_by: Pubkey,
 }
 
 impl Space for Placeholder {
-    const INIT_SPACE: usize = 8 + 8 + 32 + 4 + 2 + 2 + 8 + 8;
+    const INIT_SPACE: usize = 8 + 8 + 32 + 32 + 4 + 4 + 8 + 32;
+}
+
+impl Placeholder {
+    // The placeholder name is the collection name
+    pub fn space(collection: &Collection) -> usize {
+        Placeholder::INIT_SPACE + collection.name.len()
+    }
+}
+
+// Mint parameters PDA [b"lazy", placeholder] of a placeholder created in lazy mint mode (collection.lazy_mint), 
+// the first delivery (buy, airdrop or claim) creates the mint from them
+#[account]
+pub struct LazyMint {
+    pub uri: String,
+    pub default_frozen: bool,
+    pub symbol_override: Option<String>,
+    pub extra_attributes: Vec<Attributes>,
+}
+
+impl LazyMint {
+    pub fn space(&self) -> usize {
+        8 + (4 + self.uri.len()) + 1 + (1 + 4 + self.symbol_override.as_ref().map_or(0, String::len)) 
+            + 4 + self.extra_attributes.iter().map(|attr| 4 + attr.key.len() + 4 + attr.value.len()).sum::<usize>()
+    }
+}
+
+// Marker PDA [b"reference", reference] so a collection can be fetched from its reference without getProgramAccounts
+#[account]
+pub struct ReferenceIndex {
+    pub collection: Pubkey,
+}
+
+impl Space for ReferenceIndex {
+    const INIT_SPACE: usize = 8 + 32;
+}
+
+// Registry head PDA [b"registry"], counts the collections listed in the CollectionRegistryPage PDAs
+#[account]
+pub struct CollectionRegistry {
+    pub total_collections: u64,
+}
+
+impl Space for CollectionRegistry {
+    const INIT_SPACE: usize = 8 + 8;
+}
+
+impl CollectionRegistry {
+    // Page receiving the next created collection
+    pub fn current_page(&self) -> u32 {
+        (self.total_collections / REGISTRY_PAGE_SIZE as u64) as u32
+    }
+}
+
+// Page PDA [b"registry_page", page (u32 le)] listing up to REGISTRY_PAGE_SIZE collections in creation order, 
+// clients paginate from page 0 instead of a getProgramAccounts
+#[account]
+pub struct CollectionRegistryPage {
+    pub page: u32,
+    pub collections: Vec<Pubkey>,
+}
+
+impl Space for CollectionRegistryPage {
+    const INIT_SPACE: usize = 8 + 4 + (4 + 32 * REGISTRY_PAGE_SIZE);
+}
+
+// Marker PDA [b"uri", collection, sha256(uri)] created with a placeholder when the collection enforces unique uris
+#[account]
+pub struct UriMarker {
+    pub placeholder: Pubkey,
+}
+
+impl Space for UriMarker {
+    const INIT_SPACE: usize = 8 + 32;
+}
+
+// Receipt PDA [b"wl_receipt", collection, buyer] created by a signature whitelist buy, 
+// one whitelist mint per wallet so a signature can't be replayed
+#[account]
+pub struct WhitelistReceipt {
+    pub collection: Pubkey,
+    pub buyer: Pubkey,
+    pub minted_at: i64,
+}
+
+impl Space for WhitelistReceipt {
+    const INIT_SPACE: usize = 8 + 32 + 32 + 8;
+}
+
+#[account]
+pub struct AirdropClaim {
+    pub collection: Pubkey,
+    pub buyer: Pubkey,
+    pub created_at: i64,
+    pub claim_deadline: i64,
+}
+
+impl Space for AirdropClaim {
+    const INIT_SPACE: usize = 8 + 32 + 32 + 8 + 8;
+}
+
+impl AirdropClaim {
+    // A zero deadline never expires
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.claim_deadline != 0 && now > self.claim_deadline
+    }
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct PlaceholderMetadata {
+    pub id: u64,
+    pub collection: Pubkey,
+    pub price: f32,
+    pub time_stamp: i64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq)]
+pub enum SalePhase {
+    NotStarted,
+    Whitelist,
+    Public,
+    Ended,
+}
+
+// Fixed is the first variant so zero filled (migrated) collections keep fixed price sales
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq)]
+pub enum SaleType {
+    Fixed,
+    Dutch,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct PlaceholderAddresses {
+    pub placeholder: Pubkey,
+    pub placeholder_bump: u8,
+    pub mint: Pubkey,
+    pub mint_bump: u8,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct CollectionSupply {
+    pub remaining: u64,
+    pub public_sale_active: bool,
+    pub pause_reason: u8,
+}
+
+// Lamports above the rent exemption minimum
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct SweepableBalances {
+    pub auth: u64,
+    pub treasury: u64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq)]
 pub struct Attributes {
     pub key: String,
     pub value: String,
@@ -72,4 +378,188 @@ pub struct AiNft {
 
 impl Space for AiNft {
     const INIT_SPACE: usize = 8 + 8 + 32 + 4 + 2 + 32 + 2 + 8;
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constant::PAUSE_REASON_MAINTENANCE;
+
+    const SALE_START: i64 = 1_000;
+    const SALE_END: i64 = 2_000;
+
+    // Fresh fixed price collection as create_collection writes it, selling between SALE_START and SALE_END
+    fn collection() -> Collection {
+        Collection {
+            reference: Pubkey::new_unique(),
+            name: "Test Collection".to_string(),
+            symbol: "TEST".to_string(),
+            owner: Pubkey::new_unique(),
+            url: "https://example.com".to_string(),
+            sale_start_time: SALE_START,
+            sale_end_time: SALE_END,
+            max_supply: 10,
+            total_supply: 0,
+            mint_count: 0,
+            price: 0.3,
+            stable_id: "TEST".to_string(),
+            salt: [0; 8],
+            payout_destination: None,
+            frozen_metadata: false,
+            interest_rate: None,
+            pause_start: 0,
+            pause_end: 0,
+            price_mint: None,
+            whitelist_only: false,
+            creators: vec![],
+            metaplex_collection: None,
+            last_observed_phase: SalePhase::NotStarted,
+            gate_mint: None,
+            launch_verified: false,
+            version: COLLECTION_VERSION,
+            owner_can_create: false,
+            description: String::new(),
+            next_placeholder_id: 0,
+            bump: 255,
+            sale_type: SaleType::Fixed,
+            dutch_start_price: 0.0,
+            dutch_floor: 0.0,
+            dutch_decay_per_sec: 0.0,
+            enforce_unique_uri: false,
+            created_at: 0,
+            enable_holder_updates: false,
+            whitelist_supply_cap: 0,
+            whitelist_minted: 0,
+            pause_reason: PAUSE_REASON_NONE,
+            airdrop_rent_payer: None,
+            collection_metadata_authority: false,
+            require_memo_on_transfer: false,
+            lazy_mint: false,
+            signature_whitelist: false,
+            whitelist_end_time: 0,
+        }
+    }
+
+    #[test]
+    fn current_price() {
+        let mut collection = collection();
+        assert_eq!(collection.current_price(SALE_START + 100, 0.3), 0.3);
+
+        collection.sale_type = SaleType::Dutch;
+        collection.dutch_start_price = 2.0;
+        collection.dutch_floor = 0.5;
+        collection.dutch_decay_per_sec = 0.25;
+        // before the sale start the price doesn't grow above dutch_start_price
+        assert_eq!(collection.current_price(SALE_START - 100, 0.3), 2.0);
+        assert_eq!(collection.current_price(SALE_START, 0.3), 2.0);
+        assert_eq!(collection.current_price(SALE_START + 4, 0.3), 1.0);
+        assert_eq!(collection.current_price(SALE_START + 1_000, 0.3), 0.5);
+    }
+
+    #[test]
+    fn phase() {
+        let mut collection = collection();
+        assert!(collection.phase(SALE_START - 1) == SalePhase::NotStarted);
+        assert!(collection.phase(SALE_START) == SalePhase::Public);
+        assert!(collection.phase(SALE_END) == SalePhase::Public);
+        assert!(collection.phase(SALE_END + 1) == SalePhase::Ended);
+
+        collection.whitelist_only = true;
+        assert!(collection.phase(SALE_END) == SalePhase::Whitelist);
+        assert!(collection.phase(SALE_END + 1) == SalePhase::Ended);
+    }
+
+    #[test]
+    fn phase_falls_back_to_public_after_the_whitelist_window() {
+        let mut collection = collection();
+        collection.whitelist_end_time = SALE_START + 500;
+        assert!(collection.phase(SALE_START) == SalePhase::Whitelist);
+        assert!(collection.phase(SALE_START + 499) == SalePhase::Whitelist);
+        assert!(collection.phase(SALE_START + 500) == SalePhase::Public);
+
+        // the whitelist cap being reached ends the window early
+        collection.whitelist_supply_cap = 2;
+        collection.whitelist_minted = 1;
+        assert!(collection.phase(SALE_START) == SalePhase::Whitelist);
+        collection.whitelist_minted = 2;
+        assert!(collection.phase(SALE_START) == SalePhase::Public);
+        assert!(collection.is_public_sale_active(SALE_START));
+
+        // whitelist only collections never fall back
+        collection.whitelist_only = true;
+        assert!(collection.phase(SALE_START + 500) == SalePhase::Whitelist);
+        assert!(!collection.is_public_sale_active(SALE_START + 500));
+    }
+
+    #[test]
+    fn is_paused() {
+        let mut collection = collection();
+        assert!(!collection.is_paused(SALE_START));
+
+        collection.pause_start = SALE_START + 100;
+        collection.pause_end = SALE_START + 200;
+        assert!(!collection.is_paused(SALE_START + 99));
+        assert!(collection.is_paused(SALE_START + 100));
+        assert!(collection.is_paused(SALE_START + 199));
+        assert!(!collection.is_paused(SALE_START + 200));
+    }
+
+    #[test]
+    fn effective_pause_reason() {
+        let mut collection = collection();
+        collection.pause_start = SALE_START + 100;
+        collection.pause_end = SALE_START + 200;
+        collection.pause_reason = PAUSE_REASON_MAINTENANCE;
+        assert_eq!(collection.effective_pause_reason(SALE_START), PAUSE_REASON_NONE);
+        assert_eq!(collection.effective_pause_reason(SALE_START + 150), PAUSE_REASON_MAINTENANCE);
+        // an expired window keeps its stored reason, it isn't in effect anymore
+        assert_eq!(collection.effective_pause_reason(SALE_START + 200), PAUSE_REASON_NONE);
+        assert_eq!(collection.pause_reason, PAUSE_REASON_MAINTENANCE);
+    }
+
+    #[test]
+    fn integrity_flags() {
+        let mut collection = collection();
+        let all_flags = (1 << (INTEGRITY_VERSION + 1)) - 1;
+        assert_eq!(collection.integrity_flags(), all_flags);
+
+        collection.total_supply = collection.max_supply + 1;
+        collection.whitelist_supply_cap = 1;
+        collection.whitelist_minted = 2;
+        collection.pause_reason = PAUSE_REASON_MAINTENANCE;
+        collection.version = COLLECTION_VERSION - 1;
+        assert_eq!(
+            collection.integrity_flags(),
+            all_flags 
+                & !(1 << INTEGRITY_SUPPLY) 
+                & !(1 << INTEGRITY_WHITELIST_CAP) 
+                & !(1 << INTEGRITY_PAUSE_WINDOW) 
+                & !(1 << INTEGRITY_VERSION)
+        );
+    }
+
+    #[test]
+    fn placeholder_seed() {
+        let mut collection = collection();
+        // a zero salt keeps the original id derivation
+        assert_eq!(collection.placeholder_seed(7), 7u64.to_le_bytes());
+
+        collection.salt = 0xff_u64.to_le_bytes();
+        assert_eq!(collection.placeholder_seed(7), (7u64 ^ 0xff).to_le_bytes());
+        assert_ne!(collection.placeholder_seed(7), collection.placeholder_seed(8));
+    }
+
+    #[test]
+    fn airdrop_claim_is_expired() {
+        let mut claim = AirdropClaim {
+            collection: Pubkey::new_unique(),
+            buyer: Pubkey::new_unique(),
+            created_at: SALE_START,
+            claim_deadline: 0,
+        };
+        assert!(!claim.is_expired(i64::MAX));
+
+        claim.claim_deadline = SALE_START + 100;
+        assert!(!claim.is_expired(SALE_START + 100));
+        assert!(claim.is_expired(SALE_START + 101));
+    }
+}